@@ -1,17 +1,24 @@
 Set = frozenset # Data will be frozensets, so they can't be mutated.
 import random 
+from collections import Counter
 SINGLE="'"
 DOUBLE='"'
 
-print("fn bench(){")
+print("fn bench(mode: Mode){")
+
+weights = {} # Times each word was listed, by set name, written as word*n
+data = [] # Word lists, printed after bench so tests can use them too
 
 def output(vals, name):
-    print(f"let mut {name}: Set = [{','.join(map(lambda x: DOUBLE+x+DOUBLE, vals))}].iter().copied().collect();")#": Set = &[{",".join(map(repr, vals))}].iter().collect()")
+    counts = weights.get(name, {})
+    vals = [f"{x}*{counts[x]}" if counts.get(x, 1) > 1 else x for x in vals]
+    data.append(f"pub const {name.upper()}: &[&str] = &[{','.join(map(lambda x: DOUBLE+x+DOUBLE, vals))}];")
+    print(f"let mut {name}: Set = data::{name.upper()}.iter().copied().collect();")
 
 def output_lest(wname, lname):
     # print(println!("{}", find_regex(&mut winners, &losers));)
 
-    print(f"println!(\"{'{}'}\", find_regex(&mut {wname}, &{lname}));")
+    print(f"run(mode, \"{wname}\", &mut {wname}, &{lname});")
 
 def overall(wname, lname):
     output(globals()[wname], wname)
@@ -27,22 +34,31 @@ def phrases(text, sep='/'):
     return Set(p.upper().strip() for p in text.split(sep))
 
 
-winners = words('''washington adams jefferson jefferson madison madison monroe 
+winners_text = '''washington adams jefferson jefferson madison madison monroe 
     monroe adams jackson jackson van-buren harrison polk taylor pierce buchanan 
     lincoln lincoln grant grant hayes garfield cleveland harrison cleveland mckinley
     mckinley roosevelt taft wilson wilson harding coolidge hoover roosevelt 
     roosevelt roosevelt roosevelt truman eisenhower eisenhower kennedy johnson nixon 
-    nixon carter reagan reagan bush clinton clinton bush bush obama obama''')
-losers = words('''clinton jefferson adams pinckney pinckney clinton king adams 
+    nixon carter reagan reagan bush clinton clinton bush bush obama obama'''
+winners = words(winners_text)
+losers_text = '''clinton jefferson adams pinckney pinckney clinton king adams 
     jackson adams clay van-buren van-buren clay cass scott fremont breckinridge 
     mcclellan seymour greeley tilden hancock blaine cleveland harrison bryan bryan 
     parker bryan roosevelt hughes cox davis smith hoover landon wilkie dewey dewey 
     stevenson stevenson nixon goldwater humphrey mcgovern ford carter mondale 
-    dukakis bush dole gore kerry mccain romney''') - winners
+    dukakis bush dole gore kerry mccain romney'''
+losers = words(losers_text) - winners
+# The same, counting each time they stood, for the modes that use weights
+elected, defeated = winners, losers
+weights["elected"] = Counter(winners_text.split())
+weights["defeated"] = Counter(losers_text.split())
 
 
 
 overall("winners", "losers")
+output(elected, "elected")
+output(defeated, "defeated")
+print('if let Mode::Weighted | Mode::Tolerant = mode { run(mode, "elected", &mut elected, &defeated) }')
 
 
 boys = words('jacob mason ethan noah william liam jayden michael alexander aiden')
@@ -88,6 +104,8 @@ verbs = words('''ask believe borrow break bring buy can be able cancel change cl
     use wait wake up want watch work worry write''') - nouns
 
 overall("nouns", "adverbs")
+output(verbs, "verbs")
+print('if let Mode::Classes = mode { classes("words", &[("nouns", &nouns), ("adverbs", &adverbs), ("verbs", &verbs)]) }')
 
 randoms = Set(vars(random))
 builtins = Set(vars(__builtins__)) - randoms
@@ -199,4 +217,10 @@ scientists = phrases('''Alain Aspect / Martin Karplus / David Baltimore / Donald
 
 overall("stars", "scientists")
 
-print("}")
\ No newline at end of file
+print("}")
+print()
+print("#[rustfmt::skip]")
+print("mod data {")
+for line in data:
+    print(line)
+print("}")
@@ -0,0 +1,177 @@
+use crate::cover::{fill, prune, Bits, Limits, Table};
+use crate::h4x_re::Regex;
+use crate::rng::Rng;
+use crate::Set;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// Geometric cooling from `start` to `end` over `steps` moves
+pub struct Schedule {
+    pub start: f64,
+    pub end: f64,
+    pub steps: usize,
+}
+
+impl Schedule {
+    fn temperature(&self, step: usize) -> f64 {
+        self.start * (self.end / self.start).powf(step as f64 / self.steps as f64)
+    }
+}
+
+/// The table, plus parts made by merging that aren't in it
+struct Pool<'t> {
+    table: &'t Table<'t>,
+    index: HashMap<Regex, usize>,
+    extra: Vec<(Regex, Bits)>,
+    // For each winner, the dominant table parts matching it
+    by_word: Vec<Vec<usize>>,
+}
+
+impl Pool<'_> {
+    fn part(&self, id: usize) -> &Regex {
+        match self.table.parts.get(id) {
+            Some(x) => x,
+            None => &self.extra[id - self.table.parts.len()].0,
+        }
+    }
+
+    fn hits(&self, id: usize) -> &Bits {
+        match self.table.hits.get(id) {
+            Some(x) => x,
+            None => &self.extra[id - self.table.parts.len()].1,
+        }
+    }
+
+    fn weight(&self, id: usize) -> usize {
+        self.part(id).cost() + 1
+    }
+
+    fn cost(&self, parts: &[usize]) -> usize {
+        parts
+            .iter()
+            .map(|&x| self.weight(x))
+            .sum::<usize>()
+            .saturating_sub(1)
+    }
+
+    /// Merges two parts into one matching everything either did, if it
+    /// still misses every loser
+    fn merge(&mut self, a: usize, b: usize, losers: &Set) -> Option<usize> {
+        let merged = self.part(a).merge(self.part(b))?;
+        if let Some(&id) = self.index.get(&merged) {
+            return Some(id);
+        }
+        if losers.iter().any(|loser| merged.is_match(loser)) {
+            return None;
+        }
+        let mut hits = Bits::new(self.table.words.len());
+        for (n, word) in self.table.words.iter().enumerate() {
+            if merged.is_match(word) {
+                hits.insert(n);
+            }
+        }
+        self.index
+            .insert(merged.clone(), self.table.parts.len() + self.extra.len());
+        self.extra.push((merged, hits));
+        Some(self.table.parts.len() + self.extra.len() - 1)
+    }
+
+    /// Adds the cheapest part per new winner until everything is covered,
+    /// then drops any part the others cover
+    fn repair(&self, parts: Vec<usize>) -> Vec<usize> {
+        let len = self.table.words.len();
+        let parts = fill(
+            parts,
+            len,
+            |word| &self.by_word[word],
+            |x| self.hits(x),
+            |x| self.weight(x),
+        );
+        prune(parts, len, |x| self.hits(x), |x| self.weight(x))
+    }
+}
+
+/// Simulated annealing from the cover `start`.
+///
+/// Each move swaps a part for another that matches one of its winners,
+/// removes a part, or merges two parts of the same shape, and then repairs
+/// the cover. Table parts already miss every loser, and merged parts are
+/// checked before use, so every state is a valid answer, and the best so far
+/// is returned if `limits` expires.
+pub fn anneal(
+    table: &Table,
+    losers: &Set,
+    start: &[usize],
+    schedule: &Schedule,
+    seed: u64,
+    limits: Limits,
+) -> Vec<Regex> {
+    // Nothing to move, as there are no winners
+    if start.is_empty() {
+        return vec![];
+    }
+    let mut by_word = vec![vec![]; table.words.len()];
+    for part in table.dominant() {
+        for word in table.hits[part].iter() {
+            by_word[word].push(part);
+        }
+    }
+    let mut pool = Pool {
+        table,
+        index: table
+            .parts
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(n, x)| (x, n))
+            .collect(),
+        extra: vec![],
+        by_word,
+    };
+    let mut rng = Rng::new(seed);
+
+    let mut current = start.to_vec();
+    let mut best = current.clone();
+    for step in 0..schedule.steps {
+        if limits.expired() {
+            break;
+        }
+        let pick = rng.below(current.len());
+        let mut next = current.clone();
+        match rng.below(3) {
+            0 => {
+                let winners = pool.hits(next[pick]).iter().collect_vec();
+                let options = &pool.by_word[winners[rng.below(winners.len())]];
+                next[pick] = options[rng.below(options.len())];
+            }
+            1 => {
+                next.remove(pick);
+            }
+            _ => {
+                let other = rng.below(current.len());
+                if other == pick {
+                    continue;
+                }
+                match pool.merge(next[pick], next[other], losers) {
+                    Some(merged) => {
+                        next[pick] = merged;
+                        next.remove(other);
+                    }
+                    None => continue,
+                }
+            }
+        }
+        let next = pool.repair(next);
+
+        let delta = pool.cost(&next) as f64 - pool.cost(&current) as f64;
+        if delta <= 0.0 || rng.float() < (-delta / schedule.temperature(step)).exp() {
+            current = next;
+            if pool.cost(&current) < pool.cost(&best) {
+                best = current.clone();
+                limits.offer(pool.cost(&best));
+            }
+        }
+    }
+
+    best.into_iter().map(|x| pool.part(x).clone()).collect()
+}
@@ -0,0 +1,69 @@
+use crate::cover::{Limits, Table};
+use crate::h4x_re::Regex;
+use crate::{add_covers, cuts, exact, score, weighted_greedy, Covers, Set, Weights};
+use itertools::Itertools;
+use std::time::{Duration, Instant};
+
+pub struct Anytime {
+    pub parts: Vec<Regex>,
+    /// False if time ran out, so a better answer may exist
+    pub finished: bool,
+}
+
+/// Solves in stages, each better than the last, and returns the best so far
+/// once `budget` runs out.
+///
+/// Every winner starts out as `^word$`, and the parts of each winner in
+/// turn are added to those, for up to half the budget. Greedy picks from
+/// whatever parts there are, leaving any winners it didn't get to as whole
+/// words, and then branch and bound improves on that.
+pub fn solve(winners: &Set, losers: &Set, budget: Duration) -> Anytime {
+    let start = Instant::now();
+    let deadline = start + budget;
+    let words = winners.iter().copied().sorted().collect_vec();
+    let whole = |word: &str| Regex::new(format!("^{}$", word));
+
+    let mut covers = Covers::new();
+    add_covers(&mut covers, words.iter().map(|x| whole(x)), winners, losers);
+    let mut finished = true;
+    for word in &words {
+        if Instant::now() > start + budget / 2 {
+            finished = false;
+            break;
+        }
+        add_covers(&mut covers, cuts(format!("^{}$", word)), winners, losers);
+    }
+
+    let mut parts = weighted_greedy(
+        covers.clone(),
+        winners,
+        &Weights::new(),
+        &score::Linear(4),
+        Some(deadline),
+    );
+    let left = words
+        .iter()
+        .filter(|x| !parts.iter().any(|part| part.is_match(x)))
+        .collect_vec();
+    if !left.is_empty() || Instant::now() > deadline {
+        parts.extend(left.into_iter().map(|x| whole(x)));
+        return Anytime {
+            parts,
+            finished: false,
+        };
+    }
+
+    let table = Table::new(&covers, winners);
+    let exact = exact::solve(
+        &table,
+        &table.indices(&parts),
+        Limits {
+            deadline: Some(deadline),
+            ..Default::default()
+        },
+    );
+    Anytime {
+        parts: table.regexes(&exact.parts),
+        finished: finished && exact.proven,
+    }
+}
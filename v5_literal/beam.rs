@@ -0,0 +1,152 @@
+use crate::cover::{Bits, Limits, Table};
+use crate::rng::Rng;
+use crate::score::{Ranked, Score};
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+/// How `score` ranks a part, for the winners still `left`
+fn rank(table: &Table, part: usize, left: &Bits, score: &dyn Score) -> f64 {
+    score.score(&table.parts[part], table.hits[part].and_count(left))
+}
+
+#[derive(Clone)]
+struct State {
+    parts: Vec<usize>,
+    left: Bits,
+    // Sum of `rank` over the parts, when they were picked
+    score: f64,
+}
+
+/// Greedy, but keeping the `width` best partial covers each round instead
+/// of just one. A width of 1 is greedy with ties broken by table order.
+///
+/// Once `limits` has expired, only the best state is kept, which finishes
+/// greedily.
+pub fn beam(table: &Table, width: usize, score: &dyn Score, limits: Limits) -> Vec<usize> {
+    let mut states = vec![State {
+        parts: vec![],
+        left: Bits::full(table.words.len()),
+        score: 0.0,
+    }];
+    let mut best: Option<Vec<usize>> = None;
+
+    while !states.is_empty() {
+        let width = if limits.expired() { 1 } else { width };
+        let mut seen = HashSet::new();
+        let next = states
+            .iter()
+            .flat_map(|state| {
+                (0..table.parts.len())
+                    .filter(|&x| table.hits[x].and_count(&state.left) != 0)
+                    .map(|x| (rank(table, x, &state.left, score), x))
+                    .sorted_by_key(|&(score, x)| (Reverse(Ranked(score)), x))
+                    .take(width)
+                    .map(move |(score, x)| {
+                        let mut next = state.clone();
+                        next.parts.push(x);
+                        next.left.remove_all(&table.hits[x]);
+                        next.score += score;
+                        next
+                    })
+            })
+            .sorted_by_key(|state| Reverse(Ranked(state.score)))
+            // The same parts picked in another order are the same cover
+            .filter(|state| seen.insert(state.parts.iter().copied().sorted().collect_vec()))
+            .take(width)
+            .collect_vec();
+
+        states = vec![];
+        for state in next {
+            if !state.left.is_empty() {
+                states.push(state);
+            } else if best
+                .as_ref()
+                .is_none_or(|best| table.cost(&state.parts) < table.cost(best))
+            {
+                best = Some(state.parts);
+            }
+        }
+    }
+
+    let best = best.expect("It's not possible");
+    limits.offer(table.cost(&best));
+    best
+}
+
+/// Runs greedy `runs` times, with each score nudged by up to `jitter`, and
+/// keeps the shortest. The first run has no jitter and always happens, so
+/// this never does worse than plain greedy.
+///
+/// Later runs aren't started once `limits` has expired, and are dropped as
+/// soon as they're no shorter than the best answer so far, here or elsewhere.
+pub fn restarts(
+    table: &Table,
+    runs: usize,
+    jitter: f64,
+    seed: u64,
+    score: &dyn Score,
+    limits: Limits,
+) -> Vec<usize> {
+    let mut seeds = Rng::new(seed);
+    let mut best: Option<Vec<usize>> = None;
+    'runs: for run in 0..runs.max(1) {
+        if run != 0 && limits.expired() {
+            break;
+        }
+        // Each run gets its own stream, so dropping one early doesn't
+        // change the ones after it
+        let mut rng = Rng::new(seeds.next_u64());
+        let amount = if run == 0 { 0.0 } else { jitter };
+        let bound = best
+            .as_ref()
+            .map_or(usize::MAX, |x| table.cost(x))
+            .min(limits.best());
+        let mut left = Bits::full(table.words.len());
+        let mut parts = vec![];
+        while !left.is_empty() {
+            let part = (0..table.parts.len())
+                .filter(|&x| table.hits[x].and_count(&left) != 0)
+                .map(|x| (rank(table, x, &left, score) + amount * rng.float(), x))
+                .fold(None, |best: Option<(f64, usize)>, (score, x)| match best {
+                    Some((top, _)) if top >= score => best,
+                    _ => Some((score, x)),
+                })
+                .expect("It's not possible")
+                .1;
+            left.remove_all(&table.hits[part]);
+            parts.push(part);
+            if run != 0 && table.cost(&parts) >= bound {
+                continue 'runs;
+            }
+        }
+        limits.offer(table.cost(&parts));
+        if best
+            .as_ref()
+            .is_none_or(|best| table.cost(&parts) < table.cost(best))
+        {
+            best = Some(parts);
+        }
+    }
+    best.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data, greedy, regex_covers, score, Set};
+
+    #[test]
+    fn narrow_beam_is_greedy() {
+        let winners: Set = data::WINNERS.iter().copied().collect();
+        let losers: Set = data::LOSERS.iter().copied().collect();
+        let covers = regex_covers(&winners, &losers);
+        let table = Table::new(&covers, &winners);
+        let lazy = greedy(covers, &winners, &score::Linear(4));
+
+        assert_eq!(
+            table.render(&beam(&table, 1, &score::Linear(4), Limits::default())),
+            table.render(&table.indices(&lazy))
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::h4x_re::Regex;
+use crate::rng::Rng;
+use crate::{greedy, regex_covers, score, Set};
+
+/// How one round went
+pub struct Round {
+    pub round: usize,
+    /// Losers solved against
+    pub sample: usize,
+    pub len: usize,
+    /// Losers the answer matched, which join the sample
+    pub wrong: usize,
+}
+
+/// Solves against a random `sample` of `losers`, checks the answer against
+/// all of them, and adds every loser it matches to the sample until none
+/// are, calling `report` after each round.
+///
+/// Checking an answer is one pass over the losers, where `regex_covers`
+/// makes one per part, so this is what makes huge loser lists workable.
+/// Losers that are also winners are ignored.
+pub fn solve(
+    winners: &Set,
+    losers: &[&str],
+    sample: usize,
+    seed: u64,
+    report: &mut dyn FnMut(&Round),
+) -> Vec<Regex> {
+    let mut pool: Vec<&str> = losers
+        .iter()
+        .copied()
+        .filter(|x| !winners.contains(x))
+        .collect();
+    // Partial shuffle, so the sample is the front
+    let mut rng = Rng::new(seed);
+    let sample = sample.min(pool.len());
+    for i in 0..sample {
+        let j = i + rng.below(pool.len() - i);
+        pool.swap(i, j);
+    }
+    let mut chosen: Set = pool[..sample].iter().copied().collect();
+
+    let mut round = 0;
+    loop {
+        round += 1;
+        let parts = greedy(regex_covers(winners, &chosen), winners, &score::Linear(4));
+        let wrong = pool
+            .iter()
+            .copied()
+            .filter(|x| parts.iter().any(|part| part.is_match(x)))
+            .collect::<Vec<_>>();
+        report(&Round {
+            round,
+            sample: chosen.len(),
+            len: parts
+                .iter()
+                .map(|x| x.cost() + 1)
+                .sum::<usize>()
+                .saturating_sub(1),
+            wrong: wrong.len(),
+        });
+        if wrong.is_empty() {
+            return parts;
+        }
+        chosen.extend(wrong);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::rng::Rng;
+use crate::{greedy, regex_covers, score, Set};
+use itertools::Itertools;
+use std::ops::Add;
+
+/// How test words were classified
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Counts {
+    /// Winners matched
+    pub hits: usize,
+    /// Winners missed
+    pub misses: usize,
+    /// Losers matched
+    pub wrong: usize,
+}
+
+impl Counts {
+    pub fn precision(&self) -> f64 {
+        ratio(self.hits, self.hits + self.wrong)
+    }
+
+    pub fn recall(&self) -> f64 {
+        ratio(self.hits, self.hits + self.misses)
+    }
+
+    pub fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r == 0.0 {
+            0.0
+        } else {
+            2.0 * p * r / (p + r)
+        }
+    }
+}
+
+impl Add for Counts {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+            wrong: self.wrong + other.wrong,
+        }
+    }
+}
+
+fn ratio(x: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        x as f64 / total as f64
+    }
+}
+
+/// Deals the words, shuffled by `seed`, into `k` folds
+fn folds<'a>(words: &Set<'a>, k: usize, seed: u64) -> Vec<Vec<&'a str>> {
+    let mut words = words.iter().copied().sorted().collect_vec();
+    let mut rng = Rng::new(seed);
+    for i in (1..words.len()).rev() {
+        words.swap(i, rng.below(i + 1));
+    }
+    let mut folds = vec![vec![]; k];
+    for (n, word) in words.into_iter().enumerate() {
+        folds[n % k].push(word);
+    }
+    folds
+}
+
+/// Runs greedy on all but fold `test` of the winners and losers, and
+/// classifies the words in fold `test` with the result
+pub fn fold(winners: &Set, losers: &Set, k: usize, seed: u64, test: usize) -> Counts {
+    let (win_folds, lose_folds) = (folds(winners, k, seed), folds(losers, k, seed));
+    let (win, lose) = (training(&win_folds, test), training(&lose_folds, test));
+    let parts = greedy(regex_covers(&win, &lose), &win, &score::Linear(4));
+
+    let matched = |word: &&str| parts.iter().any(|x| x.is_match(word));
+    let hits = win_folds[test].iter().filter(|x| matched(x)).count();
+    Counts {
+        hits,
+        misses: win_folds[test].len() - hits,
+        wrong: lose_folds[test].iter().filter(|x| matched(x)).count(),
+    }
+}
+
+fn training<'a>(folds: &[Vec<&'a str>], test: usize) -> Set<'a> {
+    folds
+        .iter()
+        .enumerate()
+        .filter(|&(n, _)| n != test)
+        .flat_map(|(_, x)| x.iter().copied())
+        .collect()
+}
+
+/// Each fold tested in turn, with the counts summed
+pub fn cross_validate(winners: &Set, losers: &Set, k: usize, seed: u64) -> Counts {
+    (0..k)
+        .map(|test| fold(winners, losers, k, seed, test))
+        .fold(Counts::default(), Add::add)
+}
@@ -0,0 +1,58 @@
+use crate::h4x_re::Regex;
+use crate::{greedy, regex_covers, score, Set};
+use itertools::Itertools;
+use std::fmt;
+
+pub enum Answer {
+    /// Parts matching the winners
+    Direct(Vec<Regex>),
+    /// Parts matching the losers, to be wrapped in a negative lookahead
+    Complement(Vec<Regex>),
+}
+
+impl Answer {
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            Self::Direct(parts) => parts.iter().any(|x| x.is_match(text)),
+            Self::Complement(parts) => !parts.iter().any(|x| x.is_match(text)),
+        }
+    }
+
+    /// Matches every winner and no loser
+    pub fn separates(&self, winners: &Set, losers: &Set) -> bool {
+        winners.iter().all(|x| self.is_match(x)) && !losers.iter().any(|x| self.is_match(x))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Direct(parts) => write!(f, "{}", parts.iter().map(|x| x.to_string()).join("|")),
+            Self::Complement(parts) => write!(
+                f,
+                "^(?!.*({}))",
+                parts.iter().map(|x| x.to_string()).join("|")
+            ),
+        }
+    }
+}
+
+/// Solves both ways round, and keeps the shorter answer that separates the
+/// sets, so the losers get described when that's easier.
+pub fn solve(winners: &Set, losers: &Set) -> Answer {
+    let direct = Answer::Direct(greedy(
+        regex_covers(winners, losers),
+        winners,
+        &score::Linear(4),
+    ));
+    let complement = Answer::Complement(greedy(
+        regex_covers(losers, winners),
+        losers,
+        &score::Linear(4),
+    ));
+    vec![direct, complement]
+        .into_iter()
+        .filter(|x| x.separates(winners, losers))
+        .min_by_key(|x| x.to_string().len())
+        .expect("It's not possible")
+}
@@ -0,0 +1,297 @@
+use crate::h4x_re::Regex;
+use crate::{Covers, Ptr, Set};
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Set of winners, indexed by their position in `Table::words`
+#[derive(PartialEq, Debug, Clone, Hash, Eq)]
+pub struct Bits(Vec<u64>);
+
+impl Bits {
+    pub fn new(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    pub fn full(len: usize) -> Self {
+        let mut bits = Self::new(len);
+        for i in 0..len {
+            bits.insert(i);
+        }
+        bits
+    }
+
+    pub fn insert(&mut self, i: usize) {
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    pub fn remove(&mut self, i: usize) {
+        self.0[i / 64] &= !(1 << (i % 64));
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        (self.0[i / 64] >> (i % 64)) & 1 != 0
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&x| x == 0)
+    }
+
+    pub fn and_count(&self, other: &Self) -> usize {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(x, y)| (x & y).count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.iter().zip(&other.0).all(|(x, y)| x & !y == 0)
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        for (x, y) in self.0.iter_mut().zip(&other.0) {
+            *x |= y;
+        }
+    }
+
+    pub fn retain(&mut self, other: &Self) {
+        for (x, y) in self.0.iter_mut().zip(&other.0) {
+            *x &= y;
+        }
+    }
+
+    pub fn remove_all(&mut self, other: &Self) {
+        for (x, y) in self.0.iter_mut().zip(&other.0) {
+            *x &= !y;
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().flat_map(|(n, &word)| {
+            (0..64)
+                .filter(move |i| (word >> i) & 1 != 0)
+                .map(move |i| n * 64 + i)
+        })
+    }
+}
+
+/// When a solver should give up, and what it has to beat
+#[derive(Default, Clone, Copy)]
+pub struct Limits<'a> {
+    /// Nodes branch and bound can search
+    pub nodes: Option<usize>,
+    /// Most parts a cover can have, which only branch and bound keeps to
+    pub max_parts: Option<usize>,
+    pub deadline: Option<Instant>,
+    /// Length of the best answer found elsewhere, which is kept up to date
+    /// and pruned against
+    pub shared: Option<&'a AtomicUsize>,
+}
+
+impl Limits<'_> {
+    pub fn expired(&self) -> bool {
+        self.deadline.is_some_and(|x| Instant::now() > x)
+    }
+
+    /// Length of the best answer found elsewhere
+    pub fn best(&self) -> usize {
+        self.shared
+            .map_or(usize::MAX, |x| x.load(Ordering::Relaxed))
+    }
+
+    /// Lets everyone else know about an answer this long
+    pub fn offer(&self, len: usize) {
+        if let Some(shared) = self.shared {
+            shared.fetch_min(len, Ordering::Relaxed);
+        }
+    }
+}
+
+/// `regex_covers` flattened into vectors, so solvers can refer to parts
+/// and winners by index.
+///
+/// Parts are sorted by cost and then text, so anything iterating over them
+/// is deterministic, unlike the `HashMap` they come from.
+pub struct Table<'a> {
+    pub words: Vec<&'a str>,
+    pub parts: Vec<Regex>,
+    pub hits: Vec<Bits>,
+}
+
+impl<'a> Table<'a> {
+    pub fn new(covers: &Covers, winners: &Set<'a>) -> Self {
+        let words = winners.iter().copied().sorted().collect_vec();
+        let index: HashMap<Ptr, usize> = words
+            .iter()
+            .enumerate()
+            .map(|(n, x)| (x.as_ptr(), n))
+            .collect();
+
+        let mut rows = covers
+            .iter()
+            .map(|(part, matched)| {
+                let mut bits = Bits::new(words.len());
+                for ptr in matched {
+                    bits.insert(index[ptr]);
+                }
+                (part.clone(), bits)
+            })
+            .collect_vec();
+        rows.sort_by_cached_key(|(part, _)| (part.cost(), part.to_string()));
+        let (parts, hits) = rows.into_iter().unzip();
+
+        Self { words, parts, hits }
+    }
+
+    /// What a part adds to the length of an alternation, counting its `|`
+    pub fn weight(&self, part: usize) -> usize {
+        self.parts[part].cost() + 1
+    }
+
+    /// Length of the regex `render` would give
+    pub fn cost(&self, solution: &[usize]) -> usize {
+        solution
+            .iter()
+            .map(|&x| self.weight(x))
+            .sum::<usize>()
+            .saturating_sub(1)
+    }
+
+    pub fn find(&self, part: &Regex) -> Option<usize> {
+        self.parts.iter().position(|x| x == part)
+    }
+
+    pub fn indices(&self, parts: &[Regex]) -> Vec<usize> {
+        parts
+            .iter()
+            .map(|x| self.find(x).expect("Part not in table"))
+            .collect()
+    }
+
+    /// The cheapest part for each distinct set of winners matched. Ties go
+    /// to the first in the table.
+    pub fn distinct(&self) -> Vec<usize> {
+        let mut seen = HashSet::new();
+        (0..self.parts.len())
+            .filter(|&x| seen.insert(&self.hits[x]))
+            .collect()
+    }
+
+    /// Only the given parts, in the same order
+    pub fn keep(&self, parts: &[usize]) -> Self {
+        Self {
+            words: self.words.clone(),
+            parts: self.regexes(parts),
+            hits: parts.iter().map(|&x| self.hits[x].clone()).collect(),
+        }
+    }
+
+    /// Only the given parts and winners, in the same order
+    pub fn restrict(&self, words: &Bits, parts: &[usize]) -> Self {
+        let words = words.iter().collect_vec();
+        let hits = parts
+            .iter()
+            .map(|&part| {
+                let mut hits = Bits::new(words.len());
+                for (n, &word) in words.iter().enumerate() {
+                    if self.hits[part].contains(word) {
+                        hits.insert(n);
+                    }
+                }
+                hits
+            })
+            .collect();
+        Self {
+            words: words.iter().map(|&x| self.words[x]).collect(),
+            parts: self.regexes(parts),
+            hits,
+        }
+    }
+
+    /// Parts worth considering: no other part matches the same winners (or
+    /// more) for the same weight (or less). Ties go to the first in the table.
+    pub fn dominant(&self) -> Vec<usize> {
+        let order = (0..self.parts.len())
+            .sorted_by_key(|&x| (self.weight(x), Reverse(self.hits[x].count()), x))
+            .collect_vec();
+        let mut kept: Vec<usize> = vec![];
+        for part in order {
+            if !kept
+                .iter()
+                .any(|&x| self.hits[part].is_subset(&self.hits[x]))
+            {
+                kept.push(part);
+            }
+        }
+        kept.sort_unstable();
+        kept
+    }
+
+    pub fn regexes(&self, solution: &[usize]) -> Vec<Regex> {
+        solution.iter().map(|&x| self.parts[x].clone()).collect()
+    }
+
+    pub fn render(&self, solution: &[usize]) -> String {
+        solution
+            .iter()
+            .map(|&x| self.parts[x].to_string())
+            .join("|")
+    }
+}
+
+/// Adds the part with the least weight per new winner, out of the `options`
+/// for the first winner left, until all `len` winners are matched
+pub fn fill<'a>(
+    mut parts: Vec<usize>,
+    len: usize,
+    options: impl Fn(usize) -> &'a [usize],
+    hits: impl Fn(usize) -> &'a Bits,
+    weight: impl Fn(usize) -> usize,
+) -> Vec<usize> {
+    let mut left = Bits::full(len);
+    for &part in &parts {
+        left.remove_all(hits(part));
+    }
+    while !left.is_empty() {
+        let word = left.iter().next().unwrap();
+        let part = options(word)
+            .iter()
+            .copied()
+            .filter(|&x| hits(x).and_count(&left) != 0)
+            .min_by_key(|&x| (weight(x) * 1000 / hits(x).and_count(&left), x))
+            .expect("It's not possible");
+        left.remove_all(hits(part));
+        parts.push(part);
+    }
+    parts
+}
+
+/// Drops parts the others cover, trying the heaviest first
+pub fn prune<'a>(
+    mut parts: Vec<usize>,
+    len: usize,
+    hits: impl Fn(usize) -> &'a Bits,
+    weight: impl Fn(usize) -> usize,
+) -> Vec<usize> {
+    parts.sort_by_key(|&x| (Reverse(weight(x)), x));
+    let mut i = 0;
+    while i < parts.len() {
+        let mut left = Bits::full(len);
+        for (_, &part) in parts.iter().enumerate().filter(|&(n, _)| n != i) {
+            left.remove_all(hits(part));
+        }
+        if left.is_empty() {
+            parts.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    parts
+}
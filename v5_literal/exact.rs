@@ -0,0 +1,200 @@
+use crate::cover::{Bits, Limits, Table};
+use itertools::Itertools;
+
+pub struct Solved {
+    pub parts: Vec<usize>,
+    /// False if we ran out of nodes or time before the search finished
+    pub proven: bool,
+}
+
+struct Search<'t> {
+    table: &'t Table<'t>,
+    // For each winner, the dominant parts that match it
+    by_word: Vec<Vec<usize>>,
+    // For each part, how many winners still left it matches
+    count: Vec<usize>,
+    // For each winner, its share of the cheapest part matching it, with
+    // every winner left. Shares only go up as winners go, so these sum to a
+    // bound that's quick to keep up to date.
+    floor: Vec<f64>,
+    best: Vec<usize>,
+    best_cost: usize,
+    chosen: Vec<usize>,
+    nodes: usize,
+    limits: Limits<'t>,
+}
+
+/// Branch and bound for the cheapest set of parts covering every winner.
+///
+/// `upper` is a known cover (normally from greedy), and is returned if nothing
+/// beats it. It's empty if there isn't one, and so are the parts if nothing
+/// turns up. If an answer found elsewhere beats `upper`, a proven `Solved`
+/// only means nothing beats that.
+pub fn solve<'t>(table: &'t Table<'t>, upper: &[usize], limits: Limits<'t>) -> Solved {
+    // Swapping a part for one no heavier matching as much never costs more
+    let cands = table.dominant();
+
+    let mut by_word = vec![vec![]; table.words.len()];
+    let mut count = vec![0; table.parts.len()];
+    for &cand in &cands {
+        for word in table.hits[cand].iter() {
+            by_word[word].push(cand);
+        }
+        count[cand] = table.hits[cand].count();
+    }
+    let floor = by_word
+        .iter()
+        .map(|parts| {
+            parts
+                .iter()
+                .map(|&x| table.weight(x) as f64 / count[x] as f64)
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect_vec();
+
+    let mut search = Search {
+        table,
+        by_word,
+        count,
+        floor,
+        best: upper.to_vec(),
+        best_cost: if upper.is_empty() && !table.words.is_empty() {
+            usize::MAX
+        } else {
+            upper.iter().map(|&x| table.weight(x)).sum()
+        },
+        chosen: vec![],
+        nodes: 0,
+        limits,
+    };
+    let floor = search.floor.iter().sum();
+    let proven = search.branch(&Bits::full(table.words.len()), 0, floor);
+
+    Solved {
+        parts: search.best,
+        proven,
+    }
+}
+
+impl Search<'_> {
+    /// Returns false if the node limit or deadline was hit. `floor` is the
+    /// sum of `self.floor` over the winners `left`.
+    fn branch(&mut self, left: &Bits, cost: usize, floor: f64) -> bool {
+        if left.is_empty() {
+            if cost < self.best_cost {
+                self.best = self.chosen.clone();
+                self.best_cost = cost;
+                self.limits.offer(cost - 1);
+            }
+            return true;
+        }
+        if self
+            .limits
+            .max_parts
+            .is_some_and(|x| self.chosen.len() >= x)
+        {
+            return true;
+        }
+        self.nodes += 1;
+        if self.limits.nodes.is_some_and(|x| self.nodes > x) {
+            return false;
+        }
+        // `Instant::now` is slow enough to only check now and then
+        if self.nodes.is_multiple_of(1024) && self.limits.expired() {
+            return false;
+        }
+        // Weights count a `|` for every part, so are one over the length
+        let bound = self.best_cost.min(self.limits.best().saturating_add(1));
+        // The full bound rescans every winner left, so only bother if the
+        // quick one isn't enough
+        if cost.saturating_add(ceil(floor)) >= bound
+            || cost.saturating_add(self.lower_bound(left)) >= bound
+        {
+            return true;
+        }
+
+        // Every cover has a part matching the hardest winner, so try them all
+        let word = left.iter().min_by_key(|&x| self.by_word[x].len()).unwrap();
+        let options = self.by_word[word]
+            .iter()
+            .copied()
+            // Cheapest per new winner first, so good covers turn up early
+            .sorted_by_key(|&x| (self.table.weight(x) * 1000 / self.count[x], x))
+            .collect_vec();
+
+        for part in options {
+            let mut gone = self.table.hits[part].clone();
+            gone.retain(left);
+            let mut next = left.clone();
+            next.remove_all(&gone);
+            let floor = floor - gone.iter().map(|x| self.floor[x]).sum::<f64>();
+            self.cover(&gone, false);
+            self.chosen.push(part);
+            let finished = self.branch(&next, cost + self.table.weight(part), floor);
+            self.chosen.pop();
+            self.cover(&gone, true);
+            if !finished {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Takes the winners `gone` out of the counts, or puts them back
+    fn cover(&mut self, gone: &Bits, undo: bool) {
+        for word in gone.iter() {
+            for &part in &self.by_word[word] {
+                if undo {
+                    self.count[part] += 1;
+                } else {
+                    self.count[part] -= 1;
+                }
+            }
+        }
+    }
+
+    /// Each winner pays its share of the cheapest part that could cover it.
+    /// Any cover pays at least this much, as a part's weight is split
+    /// between the winners it matches.
+    fn lower_bound(&self, left: &Bits) -> usize {
+        let shares = left
+            .iter()
+            .map(|word| {
+                self.by_word[word]
+                    .iter()
+                    .map(|&x| self.table.weight(x) as f64 / self.count[x] as f64)
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum();
+        ceil(shares)
+    }
+}
+
+/// Rounds a sum of shares up to a whole weight, allowing for float error
+fn ceil(shares: f64) -> usize {
+    (shares - 1e-9).ceil() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data, greedy, regex_covers, score, Set};
+
+    #[test]
+    fn beats_greedy() {
+        let boys: Set = data::BOYS.iter().copied().collect();
+        let girls: Set = data::GIRLS.iter().copied().collect();
+        let covers = regex_covers(&boys, &girls);
+        let table = Table::new(&covers, &boys);
+        let upper = table.indices(&greedy(covers.clone(), &boys, &score::Linear(4)));
+        let exact = solve(&table, &upper, Limits::default());
+
+        assert!(exact.proven);
+        assert!(table.cost(&exact.parts) <= table.cost(&upper));
+        let mut left = Bits::full(table.words.len());
+        for &part in &exact.parts {
+            left.remove_all(&table.hits[part]);
+        }
+        assert!(left.is_empty());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::h4x_re::Regex;
+use crate::{greedy, score, Covers, Ptr, Set};
+use std::collections::HashSet;
+
+pub struct Point {
+    /// Winners matched
+    pub covered: usize,
+    pub len: usize,
+    pub parts: Vec<Regex>,
+}
+
+/// Coverage against length, from each prefix of the greedy answer. Greedy
+/// only takes parts matching new winners, so each point covers more than
+/// the last, and none is dominated by another.
+pub fn frontier(covers: Covers, winners: &Set) -> Vec<Point> {
+    let parts = greedy(covers.clone(), winners, &score::Linear(4));
+    let mut covered: HashSet<Ptr> = HashSet::new();
+    let mut len = 0;
+    let mut points: Vec<Point> = vec![];
+    for (n, part) in parts.iter().enumerate() {
+        covered.extend(&covers[part]);
+        len += part.cost() + (n != 0) as usize;
+        if points.last().is_none_or(|x| x.covered < covered.len()) {
+            points.push(Point {
+                covered: covered.len(),
+                len,
+                parts: parts[..=n].to_vec(),
+            });
+        }
+    }
+    points
+}
@@ -0,0 +1,67 @@
+use crate::h4x_re::Regex;
+use crate::{add_covers, dotify, greedy, score, Covers, Set};
+use itertools::Itertools;
+
+/// `find_regex` for full-match engines, where each part has to match a
+/// whole winner.
+///
+/// Candidates are written as such an engine would take them, and parsed
+/// with `Regex::new_full`, so `is_match` checks them against whole words.
+/// They cost what they're written as, so `.*x.*` is long and a word is
+/// short. Winners the same length are also merged into dotted words, as
+/// those are cheap here.
+pub fn find(winners: &Set, losers: &Set) -> Vec<Regex> {
+    let mut covers = Covers::new();
+    for word in winners {
+        add_covers(
+            &mut covers,
+            candidates(word).map(Regex::new_full),
+            winners,
+            losers,
+        );
+    }
+    let whole = winners
+        .iter()
+        .map(|x| Regex::new_full(x.to_string()))
+        .collect_vec();
+    let merged = whole
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(a, b)| a.merge(b));
+    add_covers(&mut covers, merged, winners, losers);
+    greedy(covers, winners, &score::FullMatch(4))
+}
+
+/// `word` with up to 2 dots, then every dotted cut of it up to 4 chars
+/// long, with `.*` on the sides it doesn't reach
+fn candidates(word: &str) -> impl Iterator<Item = String> + '_ {
+    let len = word.len();
+    let whole = (0..=2)
+        .flat_map(move |dots| (0..len).combinations(dots))
+        .map(move |dots| {
+            word.bytes()
+                .enumerate()
+                .map(|(n, x)| if dots.contains(&n) { '.' } else { x as char })
+                .collect()
+        });
+    let cuts = (0..len)
+        .cartesian_product(1..5)
+        .filter(move |&(start, n)| start + n <= len)
+        .flat_map(move |(start, n)| {
+            dotify(word[start..start + n].to_owned()).flat_map(move |cut| {
+                let open = format!(".*{}.*", cut);
+                let front = if start == 0 {
+                    Some(format!("{}.*", cut))
+                } else {
+                    None
+                };
+                let back = if start + n == len {
+                    Some(format!(".*{}", cut))
+                } else {
+                    None
+                };
+                std::iter::once(open).chain(front).chain(back)
+            })
+        });
+    whole.chain(cuts)
+}
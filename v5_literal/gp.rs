@@ -0,0 +1,258 @@
+use crate::cover::Bits;
+use crate::h4x_re::Regex;
+use crate::rng::Rng;
+use crate::{greedy, regex_covers, score, Set};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub struct Config {
+    pub population: usize,
+    pub generations: usize,
+    /// How much matching one more winner is worth, in characters
+    pub coverage: i64,
+    /// Longest part made from scratch, so parts aren't stuck at 4 chars
+    pub max_len: usize,
+    pub seed: u64,
+}
+
+type Genome = Vec<Regex>;
+
+struct Evolve<'a, 'w> {
+    config: &'a Config,
+    words: Vec<&'w str>,
+    losers: &'a Set<'a>,
+    alphabet: Vec<u8>,
+    // Winners each part matches, or `None` if it matches a loser
+    seen: HashMap<Regex, Option<Bits>>,
+    rng: Rng,
+}
+
+/// Evolves alternations of parts directly, rather than picking from
+/// `regex_covers`, so parts can be any length.
+///
+/// Parts that match a loser are dropped as soon as they appear, so every
+/// genome is valid. Winners nothing ends up matching get a `^word$` part.
+/// Greedy's answer is in the first population, and is what comes back if
+/// nothing evolved is shorter.
+pub fn evolve(winners: &Set, losers: &Set, config: &Config) -> Vec<Regex> {
+    if winners.is_empty() {
+        return vec![];
+    }
+    let seed = greedy(regex_covers(winners, losers), winners, &score::Linear(4));
+    let words = winners.iter().copied().sorted().collect_vec();
+    let alphabet = words
+        .iter()
+        .flat_map(|x| x.bytes())
+        .filter(|&x| x != b'.')
+        .sorted()
+        .dedup()
+        .collect();
+    let mut evolve = Evolve {
+        config,
+        words,
+        losers,
+        alphabet,
+        seen: HashMap::new(),
+        rng: Rng::new(config.seed),
+    };
+
+    let mut population = (1..config.population)
+        .map(|_| {
+            let parts = (0..1 + evolve.rng.below(evolve.words.len()))
+                .map(|_| evolve.random_part())
+                .collect();
+            evolve.valid(parts)
+        })
+        .collect_vec();
+    population.insert(0, seed.clone());
+
+    for _ in 0..config.generations {
+        let mut ranked = population
+            .into_iter()
+            .map(|x| (evolve.fitness(&x), x))
+            .sorted_by_key(|(fitness, _)| -fitness)
+            .collect_vec();
+        // Keep the two best as they are
+        let mut next = ranked.drain(..2.min(ranked.len())).collect_vec();
+        while next.len() < config.population {
+            let a = evolve.select(&ranked, &next);
+            let b = evolve.select(&ranked, &next);
+            let child = evolve.crossover(&a, &b);
+            let child = evolve.mutate(child);
+            let child = evolve.valid(child);
+            next.push((evolve.fitness(&child), child));
+        }
+        population = next.into_iter().map(|(_, x)| x).collect();
+    }
+
+    let best = population
+        .into_iter()
+        .max_by_key(|x| evolve.fitness(x))
+        .unwrap_or_default();
+    let best = evolve.complete(best);
+    if len(&best) < len(&seed) {
+        best
+    } else {
+        seed
+    }
+}
+
+impl Evolve<'_, '_> {
+    fn hits(&mut self, part: &Regex) -> Option<Bits> {
+        if let Some(x) = self.seen.get(part) {
+            return x.clone();
+        }
+        let hits = if self.losers.iter().any(|loser| part.is_match(loser)) {
+            None
+        } else {
+            let mut hits = Bits::new(self.words.len());
+            for (n, word) in self.words.iter().enumerate() {
+                if part.is_match(word) {
+                    hits.insert(n);
+                }
+            }
+            Some(hits)
+        };
+        self.seen.insert(part.clone(), hits.clone());
+        hits
+    }
+
+    /// Drops parts that match a loser, or nothing, or repeat
+    fn valid(&mut self, genome: Genome) -> Genome {
+        genome
+            .into_iter()
+            .unique()
+            .filter(|x| self.hits(x).is_some_and(|x| !x.is_empty()))
+            .collect()
+    }
+
+    fn fitness(&mut self, genome: &[Regex]) -> i64 {
+        let mut left = Bits::full(self.words.len());
+        for part in genome {
+            left.remove_all(&self.hits(part).unwrap());
+        }
+        let covered = (self.words.len() - left.count()) as i64;
+        self.config.coverage * covered - len(genome) as i64
+    }
+
+    /// Tournament of three
+    fn select(&mut self, ranked: &[(i64, Genome)], elite: &[(i64, Genome)]) -> Genome {
+        let pool = ranked.len() + elite.len();
+        (0..3)
+            .map(|_| self.rng.below(pool))
+            .map(|x| ranked.get(x).unwrap_or_else(|| &elite[x - ranked.len()]))
+            .max_by_key(|(fitness, _)| *fitness)
+            .unwrap()
+            .1
+            .clone()
+    }
+
+    /// The front of one alternation, and the back of the other
+    fn crossover(&mut self, a: &[Regex], b: &[Regex]) -> Genome {
+        let cut_a = self.rng.below(a.len() + 1);
+        let cut_b = self.rng.below(b.len() + 1);
+        a[..cut_a].iter().chain(&b[cut_b..]).cloned().collect()
+    }
+
+    /// A substring of a winner, with some letters dotted, anchored if it
+    /// touches the start or end of the word
+    fn random_part(&mut self) -> Regex {
+        let word = self.words[self.rng.below(self.words.len())];
+        let len = 1 + self.rng.below(self.config.max_len.min(word.len()));
+        let start = self.rng.below(word.len() - len + 1);
+        let mut body = word.as_bytes()[start..start + len].to_vec();
+        for x in body.iter_mut() {
+            if self.rng.below(4) == 0 {
+                *x = b'.';
+            }
+        }
+        let front = start == 0 && self.rng.below(2) == 0;
+        let back = start + len == word.len() && self.rng.below(2) == 0;
+        Regex::new(wrap(&body, front, back))
+    }
+
+    fn mutate(&mut self, mut genome: Genome) -> Genome {
+        if genome.is_empty() {
+            genome.push(self.random_part());
+            return genome;
+        }
+        let pick = self.rng.below(genome.len());
+        let text = genome[pick].to_string();
+        let front = text.starts_with('^');
+        let back = text.ends_with('$');
+        let mut body = text.as_bytes()[front as usize..text.len() - back as usize].to_vec();
+        let at = self.rng.below(body.len());
+        let letter = self.alphabet[self.rng.below(self.alphabet.len())];
+
+        match self.rng.below(8) {
+            0 => body.insert(self.rng.below(body.len() + 1), letter),
+            1 if body.len() > 1 => {
+                body.remove(at);
+            }
+            2 => body[at] = if body[at] == b'.' { letter } else { b'.' },
+            3 => {
+                genome[pick] = Regex::new(wrap(&body, !front, back));
+                return genome;
+            }
+            4 => {
+                genome[pick] = Regex::new(wrap(&body, front, !back));
+                return genome;
+            }
+            5 if body.len() > 1 => {
+                let at = 1 + self.rng.below(body.len() - 1);
+                genome[pick] = Regex::new(wrap(&body[..at], front, false));
+                genome.push(Regex::new(wrap(&body[at..], false, back)));
+                return genome;
+            }
+            6 => {
+                let other = self.rng.below(genome.len());
+                if other != pick {
+                    if let Some(merged) = genome[pick].merge(&genome[other]) {
+                        genome[pick] = merged;
+                        genome.remove(other);
+                    }
+                }
+                return genome;
+            }
+            7 => {
+                genome.push(self.random_part());
+                return genome;
+            }
+            _ => {
+                genome.remove(pick);
+                return genome;
+            }
+        }
+        genome[pick] = Regex::new(wrap(&body, front, back));
+        genome
+    }
+
+    /// Adds `^word$` for each winner the genome misses
+    fn complete(&mut self, mut genome: Genome) -> Genome {
+        let mut left = Bits::full(self.words.len());
+        for part in &genome {
+            left.remove_all(&self.hits(part).unwrap());
+        }
+        for word in left.iter() {
+            genome.push(Regex::new(format!("^{}$", self.words[word])));
+        }
+        genome
+    }
+}
+
+fn len(genome: &[Regex]) -> usize {
+    genome
+        .iter()
+        .map(|x| x.cost() + 1)
+        .sum::<usize>()
+        .saturating_sub(1)
+}
+
+fn wrap(body: &[u8], front: bool, back: bool) -> String {
+    format!(
+        "{}{}{}",
+        if front { "^" } else { "" },
+        String::from_utf8_lossy(body),
+        if back { "$" } else { "" }
+    )
+}
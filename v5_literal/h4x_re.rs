@@ -119,6 +119,20 @@ impl Regex {
         Self { binds, pattern }
     }
 
+    /// Parses a regex meant for full-match engines, where the whole text has
+    /// to match. A leading or trailing `.*` is how it says unanchored, and
+    /// every other side is anchored, so `is_match` agrees with that engine.
+    pub fn new_full(input: String) -> Self {
+        let front = input.strip_prefix(".*").unwrap_or(&input);
+        let inner = front.strip_suffix(".*").unwrap_or(front);
+        Self::new(format!(
+            "{}{}{}",
+            if front.len() == input.len() { "^" } else { "" },
+            inner,
+            if inner.len() == front.len() { "$" } else { "" }
+        ))
+    }
+
     #[cfg(test)]
 
     pub fn new_clone(input: &str) -> Self {
@@ -160,6 +174,46 @@ impl Regex {
         }) + self.pattern.len()
     }
 
+    /// Length of `to_full_string`
+    pub fn full_cost(&self) -> usize {
+        (match self.binds {
+            Binds::Front | Binds::Back => 2,
+            Binds::Both => 0,
+            Binds::Neither => 4,
+        }) + self.pattern.len()
+    }
+
+    pub fn dots(&self) -> usize {
+        match &self.pattern {
+            Pattern::NoDots(_) => 0,
+            Pattern::Dots(x) => x.bytes().filter(|&x| x == DOT).count(),
+            Pattern::DotsLit(_, front, back) => front + back,
+        }
+    }
+
+    pub fn anchors(&self) -> usize {
+        match self.binds {
+            Binds::Front | Binds::Back => 1,
+            Binds::Both => 2,
+            Binds::Neither => 0,
+        }
+    }
+
+    /// One regex matching everything either does, by dotting each position
+    /// they differ in. Only works for the same length and anchors.
+    pub fn merge(&self, other: &Self) -> Option<Self> {
+        if self.binds != other.binds || self.pattern.len() != other.pattern.len() {
+            return None;
+        }
+        let merged = self
+            .to_string()
+            .bytes()
+            .zip(other.to_string().bytes())
+            .map(|(x, y)| if x == y { x as char } else { '.' })
+            .collect();
+        Some(Self::new(merged))
+    }
+
     pub fn to_string(&self) -> String {
         format!(
             "{}{}{}",
@@ -177,6 +231,24 @@ impl Regex {
         )
     }
 
+    /// Same as `to_string`, for full-match engines
+    pub fn to_full_string(&self) -> String {
+        format!(
+            "{}{}{}",
+            if matches!(self.binds, Binds::Front | Binds::Both) {
+                ""
+            } else {
+                ".*"
+            },
+            self.pattern.str(),
+            if matches!(self.binds, Binds::Back | Binds::Both) {
+                ""
+            } else {
+                ".*"
+            },
+        )
+    }
+
     fn match_knows_pos(&self, text: &str) -> bool {
         match &self.pattern {
             Pattern::NoDots(x) => x == text,
@@ -389,10 +461,44 @@ mod tests {
         ] {
             let reg = Regex::new_clone(i);
             assert_eq!(reg.cost(), i.len());
+            assert_eq!(reg.dots(), i.matches('.').count());
+            assert_eq!(reg.anchors(), i.matches(&['^', '$'][..]).count());
             assert_eq!(&&reg.to_string(), i);
         }
     }
 
+    #[test]
+    fn full() {
+        for (search, full) in &[
+            ("^win$", "win"),
+            ("^wi.", "wi..*"),
+            ("w.n$", ".*w.n"),
+            ("wi", ".*wi.*"),
+        ] {
+            let reg = Regex::new_full(full.to_string());
+            assert_eq!(reg, Regex::new_clone(search));
+            assert_eq!(&reg.to_full_string(), full);
+            assert_eq!(reg.full_cost(), full.len());
+        }
+        let reg = Regex::new_full("w.n".to_owned());
+        assert!(reg.is_match("win") && reg.is_match("wan"));
+        assert!(!reg.is_match("twin") && !reg.is_match("wink"));
+    }
+
+    #[test]
+    fn merge() {
+        let merge = |a, b| {
+            Regex::new_clone(a)
+                .merge(&Regex::new_clone(b))
+                .map(|x| x.to_string())
+        };
+        assert_eq!(merge("^win", "^wan"), Some("^w.n".to_owned()));
+        assert_eq!(merge("abc", "xbz"), Some(".b.".to_owned()));
+        assert_eq!(merge("ab.$", "a.c$"), Some("a..$".to_owned()));
+        assert_eq!(merge("^win", "win"), None);
+        assert_eq!(merge("win", "wins"), None);
+    }
+
     #[test]
     fn no_dots() {
         reg_text!("^win$", ["win"], ["", "winn", "wwin", "wi", "in", "banana"]);
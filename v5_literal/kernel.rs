@@ -0,0 +1,148 @@
+use crate::cover::{Bits, Table};
+use itertools::Itertools;
+use std::cmp::Reverse;
+
+/// A smaller instance with the same optimum, plus the parts every optimal
+/// answer can be assumed to use.
+pub struct Kernel<'a> {
+    /// Indices into the original table
+    pub forced: Vec<usize>,
+    /// The winners `forced` misses, and the parts still worth using on them
+    pub table: Table<'a>,
+    /// For each part in `table`, its index in the original
+    pub origin: Vec<usize>,
+}
+
+impl Kernel<'_> {
+    /// Turns an answer for `table` into one for the original
+    pub fn expand(&self, solution: &[usize]) -> Vec<usize> {
+        self.forced
+            .iter()
+            .copied()
+            .chain(solution.iter().map(|&x| self.origin[x]))
+            .sorted()
+            .collect()
+    }
+}
+
+/// Applies the standard set cover reductions until none of them change
+/// anything:
+///
+/// - a part that is the only one left matching some winner is forced, and
+///   the winners it matches are done
+/// - a part matching a subset of what another part no heavier matches is
+///   dropped
+/// - a winner matched by every part that matches some other winner is
+///   dropped, since covering the other covers it
+pub fn reduce<'a>(table: &Table<'a>) -> Kernel<'a> {
+    let mut words = Bits::full(table.words.len());
+    let mut parts = (0..table.parts.len()).collect_vec();
+    let mut forced = vec![];
+
+    loop {
+        let before = (words.count(), parts.len(), forced.len());
+
+        // Dominated parts. Heaviest last, and widest first within a weight,
+        // so a part can only be dominated by one already kept.
+        let hits = |x: usize| {
+            let mut hits = table.hits[x].clone();
+            hits.retain(&words);
+            hits
+        };
+        let order = parts
+            .iter()
+            .map(|&x| (x, hits(x)))
+            .filter(|(_, hits)| !hits.is_empty())
+            .sorted_by_key(|(x, hits)| (table.weight(*x), Reverse(hits.count()), *x))
+            .collect_vec();
+        let mut kept: Vec<(usize, Bits)> = vec![];
+        for (part, hits) in order {
+            if !kept.iter().any(|(_, x)| hits.is_subset(x)) {
+                kept.push((part, hits));
+            }
+        }
+        kept.sort_unstable_by_key(|(x, _)| *x);
+
+        // Parts matching each winner, as bits over `kept`
+        let mut by_word = vec![Bits::new(kept.len()); table.words.len()];
+        for (n, (_, hits)) in kept.iter().enumerate() {
+            for word in hits.iter() {
+                by_word[word].insert(n);
+            }
+        }
+
+        // Forced parts
+        for word in words.iter().collect_vec() {
+            if words.contains(word) && by_word[word].count() == 1 {
+                let (part, hits) = &kept[by_word[word].iter().next().unwrap()];
+                forced.push(*part);
+                words.remove_all(hits);
+            }
+        }
+
+        // Dominated winners. Of winners with the same parts, the first stays.
+        let live = words.iter().collect_vec();
+        for &word in &live {
+            if live.iter().any(|&other| {
+                other != word
+                    && by_word[other].is_subset(&by_word[word])
+                    && (other < word || by_word[other] != by_word[word])
+            }) {
+                words.remove(word);
+            }
+        }
+
+        parts = kept
+            .into_iter()
+            .filter(|(x, _)| !forced.contains(x))
+            .map(|(x, _)| x)
+            .collect();
+        if (words.count(), parts.len(), forced.len()) == before {
+            break;
+        }
+    }
+
+    Kernel {
+        forced: forced.into_iter().sorted().collect(),
+        table: table.restrict(&words, &parts),
+        origin: parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cover::Limits;
+    use crate::{beam, data, exact, regex_covers, score, Set};
+
+    #[test]
+    fn same_optimum() {
+        let winners: Set = data::BOYS.iter().copied().collect();
+        let losers: Set = data::GIRLS.iter().copied().collect();
+        let covers = regex_covers(&winners, &losers);
+        let table = Table::new(&covers, &winners);
+        let kernel = reduce(&table);
+        assert!(kernel.table.parts.len() < table.parts.len());
+
+        let solve = |table: &Table| {
+            exact::solve(
+                table,
+                &beam::beam(table, 1, &score::Linear(4), Limits::default()),
+                Limits::default(),
+            )
+            .parts
+        };
+        let rest = if kernel.table.words.is_empty() {
+            vec![]
+        } else {
+            solve(&kernel.table)
+        };
+        let parts = kernel.expand(&rest);
+        assert_eq!(table.cost(&parts), table.cost(&solve(&table)));
+        let mut left = Bits::full(table.words.len());
+        for &part in &parts {
+            left.remove_all(&table.hits[part]);
+        }
+        assert!(left.is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::cover::{fill, prune, Limits, Table};
+use crate::rng::Rng;
+use itertools::Itertools;
+
+const EPS: f64 = 1e-9;
+
+/// The set cover with parts allowed to be taken fractionally
+pub struct Relaxed {
+    /// Amount of each part used, for the parts that are used at all
+    pub used: Vec<(usize, f64)>,
+    /// No cover weighs less than this
+    pub bound: f64,
+    /// False if we hit the pivot limit or `limits` expired, so `bound` may be
+    /// loose and `used` may not cover everything
+    pub optimal: bool,
+}
+
+impl Relaxed {
+    /// Lower bound on the length of any alternation from the table
+    pub fn min_cost(&self) -> usize {
+        // Weights are whole, so the bound can be rounded up
+        ((self.bound - 1e-6).ceil() as usize).saturating_sub(1)
+    }
+}
+
+/// Solves the LP relaxation of the table, with the dual simplex.
+///
+/// Rows are winners (`sum of x over parts matching it >= 1`), columns are the
+/// dominant parts and then a surplus for each row. Starting with the
+/// surpluses as the basis is dual feasible, as weights are positive, so every
+/// pivot keeps a valid lower bound, even if we stop early.
+pub fn relax(table: &Table, max_pivots: usize, limits: Limits) -> Relaxed {
+    let parts = table.dominant();
+    let rows = table.words.len();
+    let cols = parts.len() + rows;
+
+    // Each row is `-(parts matching the winner) + surplus = -1`
+    let mut tab = vec![vec![0.0; cols]; rows];
+    let mut rhs = vec![-1.0; rows];
+    for (col, &part) in parts.iter().enumerate() {
+        for row in table.hits[part].iter() {
+            tab[row][col] = -1.0;
+        }
+    }
+    for (row, line) in tab.iter_mut().enumerate() {
+        line[parts.len() + row] = 1.0;
+    }
+    let mut reduced = (0..cols)
+        .map(|col| parts.get(col).map_or(0.0, |&x| table.weight(x) as f64))
+        .collect_vec();
+    let mut basis = (parts.len()..cols).collect_vec();
+    let mut bound = 0.0;
+
+    let mut optimal = false;
+    for _ in 0..max_pivots {
+        if limits.expired() {
+            break;
+        }
+        let leave = (0..rows)
+            .filter(|&row| rhs[row] < -EPS)
+            .min_by(|&a, &b| rhs[a].partial_cmp(&rhs[b]).unwrap());
+        let leave = match leave {
+            Some(x) => x,
+            None => {
+                optimal = true;
+                break;
+            }
+        };
+        let enter = (0..cols)
+            .filter(|&col| tab[leave][col] < -EPS)
+            .map(|col| (reduced[col] / -tab[leave][col], col))
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+            .expect("It's not possible")
+            .1;
+
+        let pivot = tab[leave][enter];
+        for x in tab[leave].iter_mut() {
+            *x /= pivot;
+        }
+        rhs[leave] /= pivot;
+        let (pivot_row, pivot_rhs) = (tab[leave].clone(), rhs[leave]);
+        for row in (0..rows).filter(|&x| x != leave) {
+            let factor = tab[row][enter];
+            if factor.abs() > EPS {
+                for (x, y) in tab[row].iter_mut().zip(&pivot_row) {
+                    *x -= factor * y;
+                }
+                rhs[row] -= factor * pivot_rhs;
+            }
+        }
+        let factor = reduced[enter];
+        for (x, y) in reduced.iter_mut().zip(&pivot_row) {
+            *x -= factor * y;
+        }
+        bound += factor * pivot_rhs;
+        basis[leave] = enter;
+    }
+
+    let used = basis
+        .iter()
+        .zip(&rhs)
+        .filter(|&(&col, &x)| col < parts.len() && x > EPS)
+        .map(|(&col, &x)| (parts[col], x))
+        .sorted_by_key(|&(part, _)| part)
+        .collect();
+    Relaxed {
+        used,
+        bound,
+        optimal,
+    }
+}
+
+/// Randomized rounding: take each part with probability equal to how much
+/// of it the LP used, a few times over, then patch any gaps greedily and
+/// drop parts that turned out not to be needed. Keeps the best of `trials`,
+/// though only the first is run once `limits` has expired.
+pub fn round(
+    table: &Table,
+    relaxed: &Relaxed,
+    trials: usize,
+    seed: u64,
+    limits: Limits,
+) -> Vec<usize> {
+    let mut rng = Rng::new(seed);
+    let dominant = table.dominant();
+    let passes = (table.words.len() as f64).ln().ceil().max(1.0) as usize;
+
+    (0..trials)
+        .take_while(|&trial| trial == 0 || !limits.expired())
+        .map(|_| {
+            let mut chosen = vec![];
+            for _ in 0..passes {
+                for &(part, x) in &relaxed.used {
+                    if !chosen.contains(&part) && rng.float() < x {
+                        chosen.push(part);
+                    }
+                }
+            }
+
+            let len = table.words.len();
+            let weight = |x| table.weight(x);
+            let chosen = fill(chosen, len, |_| &dominant, |x| &table.hits[x], weight);
+            prune(chosen, len, |x| &table.hits[x], weight)
+        })
+        .inspect(|parts| limits.offer(table.cost(parts)))
+        .min_by_key(|parts| table.cost(parts))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data, exact, regex_covers, Set};
+
+    #[test]
+    fn bound_below_optimal() {
+        let pharma: Set = data::PHARMA.iter().copied().collect();
+        let cities: Set = data::CITIES.iter().copied().collect();
+        let table = Table::new(&regex_covers(&pharma, &cities), &pharma);
+        let relaxed = relax(&table, usize::MAX, Limits::default());
+        let rounded = round(&table, &relaxed, 16, 0, Limits::default());
+        let exact = exact::solve(&table, &rounded, Limits::default());
+
+        assert!(relaxed.optimal);
+        assert!(relaxed.min_cost() <= table.cost(&exact.parts));
+        assert!(table.cost(&exact.parts) <= table.cost(&rounded));
+    }
+}
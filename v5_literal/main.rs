@@ -5,11 +5,39 @@ use jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: Jemalloc = Jemalloc;
 
+mod anneal;
+mod anytime;
+mod beam;
+mod cegis;
+mod classify;
+mod complement;
+mod cover;
+mod exact;
+mod frontier;
+mod full;
+mod gp;
 mod h4x_re;
+mod kernel;
+mod lp;
+mod optimize;
+mod pins;
+mod portfolio;
+mod repair;
+mod rng;
+mod score;
+mod shape;
+mod synth;
+mod tolerant;
+mod topn;
+use cover::{Limits, Table};
 use h4x_re::Regex;
 use itertools::Itertools;
+use score::{Ranked, Score};
 
+use std::cmp::Reverse;
 use std::collections::*;
+use std::fmt;
+use std::time::Instant;
 
 type Set<'a> = HashSet<&'a str>;
 
@@ -18,56 +46,657 @@ const DOT: u8 = b'.';
 const END: u8 = b'$';
 
 pub fn main() {
-    bench();
+    bench(Mode::parse(std::env::args().nth(1)));
 }
 
 type Ptr = *const u8;
+type Covers = HashMap<Regex, HashSet<Ptr>>;
+/// How much each word counts, if not 1
+type Weights = HashMap<Ptr, usize>;
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Greedy,
+    Weighted,
+    Exact,
+    Beam,
+    Restart,
+    Optimize,
+    Tune,
+    Anytime,
+    Lp,
+    Anneal,
+    Gp,
+    Synth,
+    Portfolio,
+    Kernel,
+    Prune,
+    Classes,
+    Complement,
+    Tolerant,
+    Full,
+    Pins,
+    Shape,
+    Top,
+    Frontier,
+    Repair,
+    Cegis,
+    Classify,
+}
+
+impl Mode {
+    fn parse(arg: Option<String>) -> Self {
+        match arg.as_deref() {
+            None | Some("greedy") => Self::Greedy,
+            Some("weighted") => Self::Weighted,
+            Some("exact") => Self::Exact,
+            Some("beam") => Self::Beam,
+            Some("restart") => Self::Restart,
+            Some("optimize") => Self::Optimize,
+            Some("tune") => Self::Tune,
+            Some("anytime") => Self::Anytime,
+            Some("lp") => Self::Lp,
+            Some("anneal") => Self::Anneal,
+            Some("gp") => Self::Gp,
+            Some("synth") => Self::Synth,
+            Some("portfolio") => Self::Portfolio,
+            Some("kernel") => Self::Kernel,
+            Some("prune") => Self::Prune,
+            Some("classes") => Self::Classes,
+            Some("complement") => Self::Complement,
+            Some("tolerant") => Self::Tolerant,
+            Some("full") => Self::Full,
+            Some("pins") => Self::Pins,
+            Some("shape") => Self::Shape,
+            Some("top") => Self::Top,
+            Some("frontier") => Self::Frontier,
+            Some("repair") => Self::Repair,
+            Some("cegis") => Self::Cegis,
+            Some("classify") => Self::Classify,
+            Some(x) => panic!("Unknown mode {}", x),
+        }
+    }
+}
+
+/// A word written `word*weight` with a weight that isn't a number
+#[derive(Debug)]
+struct BadWeight<'a>(&'a str);
+
+impl fmt::Display for BadWeight<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bad weight in {}", self.0)
+    }
+}
+
+/// Splits the weight off words written `word*weight`
+fn weighted<'a>(words: &Set<'a>) -> Result<(Set<'a>, Weights), BadWeight<'a>> {
+    let mut weights = Weights::new();
+    let words = words
+        .iter()
+        .map(|&x| match x.rfind('*') {
+            Some(at) => {
+                let word = &x[..at];
+                let weight = x[at + 1..].parse().map_err(|_| BadWeight(x))?;
+                weights.insert(word.as_ptr(), weight);
+                Ok(word)
+            }
+            None => Ok(x),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((words, weights))
+}
+
+fn run(mode: Mode, name: &str, winners: &mut Set, losers: &Set) {
+    let (mut winners, win_weights, losers, lose_weights) =
+        match (weighted(winners), weighted(losers)) {
+            (Ok((winners, win_weights)), Ok((losers, lose_weights))) => {
+                (winners, win_weights, losers, lose_weights)
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                println!("{}: {}", name, err);
+                return;
+            }
+        };
+    let (winners, losers) = (&mut winners, &losers);
+    match mode {
+        Mode::Greedy => println!("{}", find_regex(winners, losers)),
+        Mode::Weighted => {
+            let parts = weighted_greedy(
+                regex_covers(winners, losers),
+                winners,
+                &win_weights,
+                &score::Linear(4),
+                None,
+            )
+            .into_iter()
+            .map(|x| x.to_string())
+            .join("|");
+            println!("{}: {} {}", name, parts.len(), parts);
+        }
+        Mode::Exact => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let upper = table.indices(&greedy(covers.clone(), winners, &score::Linear(4)));
+            let exact = exact::solve(
+                &table,
+                &upper,
+                Limits {
+                    nodes: Some(1_000_000),
+                    ..Default::default()
+                },
+            );
+            println!(
+                "{}: greedy {} {}",
+                name,
+                table.cost(&upper),
+                table.render(&upper)
+            );
+            println!(
+                "{}: {} {} {}",
+                name,
+                if exact.proven { "optimal" } else { "best" },
+                table.cost(&exact.parts),
+                table.render(&exact.parts)
+            );
+        }
+        Mode::Beam => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let parts = beam::beam(&table, 8, &score::Linear(4), Limits::default());
+            println!("{}: {} {}", name, table.cost(&parts), table.render(&parts));
+        }
+        Mode::Restart => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let parts = beam::restarts(&table, 64, 3.0, 0, &score::Linear(4), Limits::default());
+            println!("{}: {} {}", name, table.cost(&parts), table.render(&parts));
+        }
+        Mode::Optimize => {
+            let before = greedy(regex_covers(winners, losers), winners, &score::Linear(4));
+            let after = optimize::optimize(before.clone(), winners, losers);
+            let before = before.into_iter().map(|x| x.to_string()).join("|");
+            let after = after.into_iter().map(|x| x.to_string()).join("|");
+            println!(
+                "{}: saved {} chars, {} -> {}",
+                name,
+                before.len() - after.len(),
+                before,
+                after
+            );
+        }
+        Mode::Tune => {
+            let (score, parts) = score::tune(&regex_covers(winners, losers), winners);
+            let parts = parts.into_iter().map(|x| x.to_string()).join("|");
+            println!("{}: {:?} {} {}", name, score, parts.len(), parts);
+        }
+        Mode::Anytime => {
+            let solved = anytime::solve(winners, losers, std::time::Duration::from_secs(1));
+            let parts = solved.parts.into_iter().map(|x| x.to_string()).join("|");
+            println!(
+                "{}: {} {} {}",
+                name,
+                if solved.finished {
+                    "finished"
+                } else {
+                    "stopped"
+                },
+                parts.len(),
+                parts
+            );
+        }
+        Mode::Lp => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let upper = table.indices(&greedy(covers.clone(), winners, &score::Linear(4)));
+            let relaxed = lp::relax(&table, 10_000, Limits::default());
+            let rounded = lp::round(&table, &relaxed, 64, 0, Limits::default());
+            println!(
+                "{}: greedy {}, lower bound {}{}, gap {:.1}%",
+                name,
+                table.cost(&upper),
+                relaxed.min_cost(),
+                if relaxed.optimal {
+                    ""
+                } else {
+                    " (stopped early)"
+                },
+                100.0 * (table.cost(&upper) - relaxed.min_cost()) as f64
+                    / table.cost(&upper) as f64
+            );
+            println!(
+                "{}: rounded {} {}",
+                name,
+                table.cost(&rounded),
+                table.render(&rounded)
+            );
+        }
+        Mode::Anneal => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let start = table.indices(&greedy(covers.clone(), winners, &score::Linear(4)));
+            let schedule = anneal::Schedule {
+                start: 2.0,
+                end: 0.05,
+                steps: 20_000,
+            };
+            let parts = anneal::anneal(&table, losers, &start, &schedule, 0, Limits::default());
+            let parts = parts.into_iter().map(|x| x.to_string()).join("|");
+            println!(
+                "{}: greedy {}, annealed {} {}",
+                name,
+                table.cost(&start),
+                parts.len(),
+                parts
+            );
+        }
+        Mode::Gp => {
+            let config = gp::Config {
+                population: 200,
+                generations: 300,
+                coverage: 8,
+                max_len: 8,
+                seed: 0,
+            };
+            let parts = gp::evolve(winners, losers, &config);
+            let parts = parts.into_iter().map(|x| x.to_string()).join("|");
+            println!("{}: {} {}", name, parts.len(), parts);
+        }
+        Mode::Synth => {
+            match synth::synthesize(winners, losers, 16, std::time::Duration::from_secs(5)) {
+                Some(parts) => {
+                    let parts = parts.into_iter().map(|x| x.to_string()).join("|");
+                    println!("{}: shortest {} {}", name, parts.len(), parts);
+                }
+                None => println!("{}: gave up", name),
+            }
+        }
+        Mode::Portfolio => {
+            let raced = portfolio::race(winners, losers, std::time::Duration::from_secs(2));
+            let parts = raced.parts.into_iter().map(|x| x.to_string()).join("|");
+            println!("{}: {} {} {}", name, raced.strategy, parts.len(), parts);
+        }
+        Mode::Kernel => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let kernel = kernel::reduce(&table);
+            println!(
+                "{}: winners {} -> {}, parts {} -> {}, forced {}",
+                name,
+                table.words.len(),
+                kernel.table.words.len(),
+                table.parts.len(),
+                kernel.table.parts.len(),
+                kernel.forced.len()
+            );
+            let (rest, proven) = if kernel.table.words.is_empty() {
+                (vec![], true)
+            } else {
+                let upper = beam::beam(&kernel.table, 1, &score::Linear(4), Limits::default());
+                let exact = exact::solve(
+                    &kernel.table,
+                    &upper,
+                    Limits {
+                        nodes: Some(1_000_000),
+                        ..Default::default()
+                    },
+                );
+                (exact.parts, exact.proven)
+            };
+            let parts = kernel.expand(&rest);
+            println!(
+                "{}: {} {} {}",
+                name,
+                if proven { "optimal" } else { "best" },
+                table.cost(&parts),
+                table.render(&parts)
+            );
+        }
+        Mode::Prune => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let pruned = table.keep(&table.dominant());
+            println!(
+                "{}: parts {} -> distinct {} -> dominant {}",
+                name,
+                table.parts.len(),
+                table.distinct().len(),
+                pruned.parts.len()
+            );
+            let upper = beam::beam(&pruned, 1, &score::Linear(4), Limits::default());
+            let exact = exact::solve(
+                &pruned,
+                &upper,
+                Limits {
+                    nodes: Some(1_000_000),
+                    ..Default::default()
+                },
+            );
+            println!(
+                "{}: {} {}",
+                name,
+                pruned.cost(&exact.parts),
+                pruned.render(&exact.parts)
+            );
+        }
+        Mode::Classes => classes(name, &[(name, winners), ("losers", losers)]),
+        Mode::Complement => {
+            let answer = complement::solve(winners, losers).to_string();
+            println!("{}: {} {}", name, answer.len(), answer);
+        }
+        Mode::Pins => {
+            // Parts after the mode, with a `!` in front to forbid them
+            let args = std::env::args().skip(2).collect_vec();
+            let (forbidden, pinned): (Vec<_>, Vec<_>) = args
+                .iter()
+                .map(String::as_str)
+                .partition(|x| x.starts_with('!'));
+            let pins = pins::Pins {
+                pinned,
+                forbidden: forbidden.into_iter().map(|x| &x[1..]).collect(),
+            };
+            match pins::find_regex_with(winners, losers, &pins) {
+                Ok(parts) => {
+                    let parts = parts.into_iter().map(|x| x.to_string()).join("|");
+                    println!("{}: {} {}", name, parts.len(), parts);
+                }
+                Err(err) => println!("{}: {}", name, err),
+            }
+        }
+        Mode::Classify => {
+            let show = |label: &str, counts: classify::Counts| {
+                println!(
+                    "{}: {} precision {:.2} recall {:.2} f1 {:.2}",
+                    name,
+                    label,
+                    counts.precision(),
+                    counts.recall(),
+                    counts.f1()
+                )
+            };
+            // A quarter held out, then 5-fold cross-validation
+            show("holdout", classify::fold(winners, losers, 4, 0, 0));
+            show("5-fold", classify::cross_validate(winners, losers, 5, 0));
+        }
+        Mode::Cegis => {
+            // Losers from the file after the mode, one per line, if given
+            let file = std::env::args()
+                .nth(2)
+                .map(|x| std::fs::read_to_string(x).expect("Can't read losers"));
+            let mut all = match &file {
+                Some(text) => text.lines().collect_vec(),
+                None => losers.iter().copied().collect(),
+            };
+            all.sort_unstable();
+            let parts = cegis::solve(winners, &all, 20, 0, &mut |round| {
+                println!(
+                    "{}: round {}, {} losers, length {}, {} wrong",
+                    name, round.round, round.sample, round.len, round.wrong
+                )
+            });
+            println!(
+                "{}: {}",
+                name,
+                parts.into_iter().map(|x| x.to_string()).join("|")
+            );
+        }
+        Mode::Repair => {
+            // The first winner turns out to be a loser
+            let previous = find_regex(winners, losers);
+            let moved = winners.iter().copied().min().unwrap();
+            let mut losers = losers.clone();
+            losers.insert(moved);
+            winners.remove(moved);
+
+            let repaired = repair::repair(&previous, winners, &losers);
+            let parts = repaired.parts.iter().map(|x| x.to_string()).join("|");
+            println!("{}: {} to losers: {}", name, moved, repaired.diff());
+            println!(
+                "{}: repaired {}, fresh {}",
+                name,
+                parts.len(),
+                find_regex(winners, &losers).len()
+            );
+        }
+        Mode::Frontier => {
+            // No winners means no percentages of them
+            if winners.is_empty() {
+                return;
+            }
+            let points = frontier::frontier(regex_covers(winners, losers), winners);
+            for point in &points {
+                println!(
+                    "{}: {}% {} {}",
+                    name,
+                    point.covered * 100 / winners.len(),
+                    point.len,
+                    point.parts.iter().map(|x| x.to_string()).join("|")
+                );
+            }
+            for &percent in &[50, 75, 90, 100] {
+                let point = points
+                    .iter()
+                    .find(|x| x.covered * 100 >= percent * winners.len())
+                    .unwrap();
+                println!("{}: {}% needs {}", name, percent, point.len);
+            }
+        }
+        Mode::Top => {
+            let covers = regex_covers(winners, losers);
+            let table = Table::new(&covers, winners);
+            let top = topn::top(&table, 5, std::time::Duration::from_secs(1));
+            for (n, parts) in top.answers.into_iter().enumerate() {
+                println!(
+                    "{}: #{} {} {}",
+                    name,
+                    n + 1,
+                    table.cost(&parts),
+                    table.render(&parts)
+                );
+            }
+            if !top.proven {
+                println!("{}: not proven", name);
+            }
+        }
+        Mode::Shape => {
+            let shapes = [
+                (
+                    "literal",
+                    shape::Shape {
+                        max_dots: Some(0),
+                        max_len: Some(8),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "unanchored",
+                    shape::Shape {
+                        no_anchors: true,
+                        max_dots: Some(1),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "10 parts",
+                    shape::Shape {
+                        max_parts: Some(10),
+                        ..Default::default()
+                    },
+                ),
+            ];
+            for (label, shape) in shapes.iter() {
+                match shape::find(winners, losers, shape, std::time::Duration::from_secs(1)) {
+                    Ok(parts) => {
+                        let parts = parts.into_iter().map(|x| x.to_string()).join("|");
+                        println!("{}: {} {} {}", name, label, parts.len(), parts);
+                    }
+                    Err(err) => println!("{}: {} {}", name, label, err),
+                }
+            }
+        }
+        Mode::Full => {
+            let parts = full::find(winners, losers)
+                .into_iter()
+                .map(|x| x.to_full_string())
+                .join("|");
+            println!("{}: {} {}", name, parts.len(), parts);
+        }
+        Mode::Tolerant => {
+            for objective in [
+                tolerant::Objective::Budget {
+                    misses: 1,
+                    wrong: 1,
+                },
+                tolerant::Objective::Golf { points: 10 },
+            ]
+            .iter()
+            {
+                let found =
+                    tolerant::solve(winners, losers, &win_weights, &lose_weights, *objective);
+                let parts = found.parts.iter().map(|x| x.to_string()).join("|");
+                println!(
+                    "{}: {:?}{} {} {} missed {:?} wrong {:?}",
+                    name,
+                    objective,
+                    if found.fits { "" } else { " over budget" },
+                    parts.len(),
+                    parts,
+                    found.missed,
+                    found.wrong
+                );
+            }
+        }
+    }
+}
 
 fn find_regex(winners: &mut Set, losers: &Set) -> String {
-    let mut winner_ptr: HashSet<Ptr> = winners.iter().copied().map(str::as_ptr).collect();
+    let covers = regex_covers(winners, losers);
+    greedy(covers, winners, &score::Linear(4))
+        .into_iter()
+        .map(|x| x.to_string())
+        .join("|")
+}
+
+fn classes(name: &str, groups: &[(&str, &Set)]) {
+    let separated = find_regexes(groups);
+    for (group, regex) in separated.regexes {
+        println!("{}: {} {} {}", name, group, regex.len(), regex);
+    }
+    println!("{}: total {}", name, separated.cost);
+}
+
+/// A regex per group, each matching its own members and no other group's
+struct Separated<'a> {
+    regexes: Vec<(&'a str, String)>,
+    /// Total length of the regexes
+    cost: usize,
+}
+
+/// `find_regex` for any number of labelled groups, with the other groups
+/// together as losers. A word in several groups belongs to each, so it's
+/// never a loser.
+fn find_regexes<'a>(groups: &[(&'a str, &Set)]) -> Separated<'a> {
+    let regexes = groups
+        .iter()
+        .map(|&(name, winners)| {
+            let losers: Set = groups
+                .iter()
+                .flat_map(|(_, x)| x.iter().copied())
+                .filter(|x| !winners.contains(x))
+                .collect();
+            (name, find_regex(&mut winners.clone(), &losers))
+        })
+        .collect_vec();
+    let cost = regexes.iter().map(|(_, x)| x.len()).sum();
+    Separated { regexes, cost }
+}
+
+/// Picks the best scoring part until every winner is matched, with ties
+/// going to the cheaper part and then by text.
+///
+/// Scores only fall as winners get covered, so each part sits in a heap
+/// under an old score, and is only rescored when it gets to the top.
+fn greedy(covers: Covers, winners: &Set, score: &dyn Score) -> Vec<Regex> {
+    weighted_greedy(covers, winners, &Weights::new(), score, None)
+}
 
-    let mut covers = regex_covers(winners, losers);
+/// `greedy` with each winner counting its weight rather than 1. If it
+/// gets to `deadline` first, it stops with whatever parts it has, so some
+/// winners may be left unmatched.
+fn weighted_greedy(
+    covers: Covers,
+    winners: &Set,
+    weights: &Weights,
+    score: &dyn Score,
+    deadline: Option<Instant>,
+) -> Vec<Regex> {
+    let weight = |ptr: &Ptr| weights.get(ptr).copied().unwrap_or(1);
+    let mut winner_ptr: HashSet<Ptr> = winners.iter().copied().map(str::as_ptr).collect();
+    let mut covers = covers.into_iter().collect_vec();
+    covers.sort_by_cached_key(|(reg, _)| (reg.cost(), reg.to_string()));
+    let mut heap: BinaryHeap<_> = covers
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, matching))| !matching.is_empty())
+        .map(|(n, (reg, matching))| {
+            let hits = matching.iter().map(weight).sum();
+            (Ranked(score.score(reg, hits)), Reverse(n), 0)
+        })
+        .collect();
     let mut solutions: Vec<Regex> = vec![];
     while !winner_ptr.is_empty() {
-        let best = covers.iter().max_by_key(|(reg, matching)| {
-            4 * matching.intersection(&winner_ptr).count() as i64 - reg.cost() as i64
-        });
-        if let Some((part, matched)) = best {
+        if deadline.is_some_and(|x| Instant::now() > x) {
+            break;
+        }
+        let (_, Reverse(n), round) = heap.pop().expect("It's not possible");
+        let (part, matched) = &covers[n];
+        if round == solutions.len() {
             solutions.push(part.clone());
             winner_ptr.retain(|x| !matched.contains(x));
-            covers.retain(|_, matched| matched.intersection(&winner_ptr).next().is_some());
         } else {
-            panic!("It's not possible")
+            let hits = matched.intersection(&winner_ptr).map(weight).sum();
+            if hits != 0 {
+                heap.push((Ranked(score.score(part, hits)), Reverse(n), solutions.len()));
+            }
         }
     }
-    solutions.into_iter().map(|x| x.to_string()).join("|")
+    solutions
 }
 
 #[inline(never)]
-fn regex_covers<'a>(winners: &'a Set<'a>, losers: &'a Set<'a>) -> HashMap<Regex, HashSet<Ptr>> {
-    let whole = winners.iter().map(|x| format!("^{}$", x));
-    let parts = whole
-        .clone()
-        .flat_map(subparts)
-        .flat_map(dotify)
-        .map(Regex::new)
-        .filter(move |part| losers.iter().all(|loser| !part.is_match(loser)));
-    whole
-        .map(Regex::new)
-        .chain(parts)
-        .map(|pat| {
-            // Because Borrowck
-            let hm = winners
+fn regex_covers<'a>(winners: &'a Set<'a>, losers: &'a Set<'a>) -> Covers {
+    let mut covers = Covers::new();
+    for word in winners {
+        add_covers(&mut covers, cuts(format!("^{}$", word)), winners, losers);
+    }
+    covers
+}
+
+/// Adds each of `parts` not already in `covers` that misses every loser,
+/// with the winners it matches
+fn add_covers(
+    covers: &mut Covers,
+    parts: impl Iterator<Item = Regex>,
+    winners: &Set,
+    losers: &Set,
+) {
+    for part in parts {
+        if !covers.contains_key(&part) && losers.iter().all(|loser| !part.is_match(loser)) {
+            let matched = winners
                 .iter()
-                .filter(|win| pat.is_match(win))
+                .filter(|win| part.is_match(win))
                 .copied()
                 .map(str::as_ptr)
                 .collect();
-            (pat, hm)
-        })
-        .collect()
+            covers.insert(part, matched);
+        }
+    }
+}
 
-    //(pat, winners.iter().filter(|win| pat.is_match(win)).copied().collect()))
+/// `whole`, then every dotted cut of it up to 4 chars long
+fn cuts(whole: String) -> impl Iterator<Item = Regex> {
+    std::iter::once(whole.clone())
+        .chain(subparts(whole, 4).flat_map(dotify))
+        .map(Regex::new)
 }
 
 fn dotify(word: String) -> impl Iterator<Item = String> {
@@ -98,10 +727,11 @@ fn set_dots(word: &mut str, n: usize) {
     }
 }
 
-fn subparts(word: String) -> impl Iterator<Item = String> {
+/// Every cut of `word` up to `longest` chars long
+fn subparts(word: String, longest: usize) -> impl Iterator<Item = String> {
     let len = word.len();
     (0..=len)
-        .cartesian_product(1..5)
+        .cartesian_product(1..=longest)
         .map(|(start, offset)| (start, start + offset))
         .filter(move |(_, end)| *end <= len)
         .map(move |(start, end)| word[start..end].to_owned())
@@ -109,35 +739,67 @@ fn subparts(word: String) -> impl Iterator<Item = String> {
 
 #[rustfmt::skip]
 #[allow(clippy::blacklisted_name)]
-fn bench(){
-    let mut winners: Set = ["bush","clinton","monroe","madison","hayes","kennedy","reagan","jefferson","mckinley","taft","wilson","harding","jackson","garfield","truman","van-buren","polk","johnson","roosevelt","carter","cleveland","washington","grant","coolidge","nixon","eisenhower","obama","lincoln","adams","hoover","taylor","harrison","pierce","buchanan"].iter().copied().collect();
-    let losers: Set = ["tilden","greeley","dukakis","hughes","smith","landon","fremont","scott","ford","pinckney","gore","king","humphrey","cass","mcclellan","bryan","mcgovern","davis","mccain","clay","cox","dewey","parker","wilkie","stevenson","romney","blaine","seymour","hancock","breckinridge","kerry","goldwater","dole","mondale"].iter().copied().collect();
-    println!("{}", find_regex(&mut winners, &losers));
-    let mut boys: Set = ["ethan","jayden","alexander","noah","liam","jacob","mason","aiden","michael","william"].iter().copied().collect();
-    let girls: Set = ["madison","isabella","elizabeth","olivia","emily","emma","ava","mia","abigail","sophia"].iter().copied().collect();
-    println!("{}", find_regex(&mut boys, &girls));
-    let mut pharma: Set = ["singulair","epogen","ablify","advair","nexium","seroquel","crestor","actos","plavix","lipitor"].iter().copied().collect();
-    let cities: Set = ["capetown","riga","shanghai","vancouver","auckland","paris","chicago","trinidad","adelaide","zurich"].iter().copied().collect();
-    println!("{}", find_regex(&mut pharma, &cities));
-    let mut foo: Set = ["padfoot","foolery","foothot","fooster","foolish","jawfoot","prefool","dogfoot","catfoot","afoot","unfool","fanfoot","foody","nonfood","footle","footway","mafoo","sfoot","footage","hotfoot","footpad"].iter().copied().collect();
-    let bar: Set = ["unfold","crooked","manlike","palazzi","sixfold","Silipan","altared","forest","tarrock","marly","folksy","chandoo","crenel","Iberic","Aymoro","Atlas","Ormazd","Mahran","fardo","hebamic","idgah"].iter().copied().collect();
-    println!("{}", find_regex(&mut foo, &bar));
-    let mut nouns: Set = ["air","hour","school","time","program","health","city","house","world","case","guy","hand","father","education","country","friend","eye","morning","party","kind","game","member","lot","company","month","issue","side","information","business","book","number","work","child","group","problem","history","place","back","line","level","year","person","job","team","day","president","family","moment","service","body","result","question","government","story","teacher","research","people","law","force","art","week","parent","idea","kid","room","home","water","thing","mother","end","night","reason","community","study","fact","life","change","door","area","others","point","man","war","woman","way","right","minute","name","girl","system","car","money","word","office","power","student","state","head","face","part"].iter().copied().collect();
-    let adverbs: Set = ["never","in","especially","little","quickly","recently","always","pretty","out","here","ago","today","directly","far","where","tonight","again","often","however","only","maybe","enough","just","as","that","why","well","least","close","more","soon","on","fast","away","up","perhaps","course","finally","simply","all","before","to","how","sometimes","almost","then","probably","exactly","once","long","now","usually","down","suddenly","forward","rather","yet","hard","ok","clearly","already","much","off","also","better","else","when","early","even","quite","of","together","certainly","less","over","around","still","alone","thus","eventually","ahead","very","instead","indeed","most","best","ever","later","particularly","nearly","either","there","both","about","really","actually","no","so"].iter().copied().collect();
-    println!("{}", find_regex(&mut nouns, &adverbs));
-    let mut randoms: Set = ["setstate","_e","_Sequence","_inst","_os","weibullvariate","_sqrt","getrandbits","_bisect","_pi","LOG4","_urandom","__name__","_ceil","_sha512","_warn","normalvariate","vonmisesvariate","_MethodType","seed","randrange","__package__","SystemRandom","randint","choice","_test","shuffle","getstate","__all__","sample","TWOPI","_BuiltinMethodType","Random","__builtins__","_Set","_test_generator","paretovariate","__file__","lognormvariate","_sin","betavariate","SG_MAGICCONST","__loader__","_cos","RECIP_BPF","uniform","gammavariate","expovariate","gauss","_random","triangular","_exp","__cached__","_acos","_log","BPF","__doc__","__spec__","choices","NV_MAGICCONST","random","_itertools"].iter().copied().collect();
-    let builtins: Set = ["help","LookupError","IndexError","PendingDeprecationWarning","IOError","globals","NameError","ConnectionError","OSError","ProcessLookupError","bytes","UnicodeError","ResourceWarning","ImportWarning","BytesWarning","KeyError","quit","KeyboardInterrupt","dir","credits","breakpoint","len","tuple","BufferError","id","compile","next","BlockingIOError","ConnectionResetError","GeneratorExit","copyright","memoryview","sorted","min","AssertionError","SystemError","StopAsyncIteration","bytearray","enumerate","max","type","callable","any","ord","range","exec","ArithmeticError","open","bin","__import__","ValueError","getattr","oct","ZeroDivisionError","hash","PermissionError","all","divmod","ReferenceError","RuntimeError","EOFError","sum","RecursionError","pow","float","locals","reversed","slice","UnicodeDecodeError","SyntaxWarning","ChildProcessError","IsADirectoryError","DeprecationWarning","abs","classmethod","isinstance","hex","UnicodeWarning","False","chr","issubclass","frozenset","str","FutureWarning","hasattr","print","EnvironmentError","IndentationError","TypeError","ConnectionRefusedError","set","FloatingPointError","round","AttributeError","TabError","BaseException","ModuleNotFoundError","dict","super","Exception","NotImplemented","Ellipsis","filter","property","UnboundLocalError","ConnectionAbortedError","eval","format","zip","RuntimeWarning","Warning","NotADirectoryError","SyntaxError","UnicodeTranslateError","OverflowError","None","object","setattr","UnicodeEncodeError","True","input","list","UserWarning","map","license","__debug__","NotImplementedError","iter","vars","SystemExit","BrokenPipeError","ascii","FileExistsError","InterruptedError","bool","StopIteration","int","repr","ImportError","delattr","__build_class__","FileNotFoundError","staticmethod","MemoryError","complex","exit","TimeoutError"].iter().copied().collect();
-    println!("{}", find_regex(&mut randoms, &builtins));
-    let mut starwars: Set = ["ATTACK OF THE CLONES","THE PHANTOM MENACE","REVENGE OF THE SITH","THE EMPIRE STRIKES BACK","A NEW HOPE","RETURN OF THE JEDI"].iter().copied().collect();
-    let startrek: Set = ["GENERATIONS","THE WRATH OF KHAN","THE SEARCH FOR SPOCK","NEMESIS","THE UNDISCOVERED COUNTRY","THE FINAL FRONTIER","INSURRECTION","FIRST CONTACT","THE VOYAGE HOME"].iter().copied().collect();
-    println!("{}", find_regex(&mut starwars, &startrek));
-    let mut dogs: Set = ["'LABRADOR RETRIEVERS","CARDIGAN WELSH CORGIS","AKITAS","VIZSLAS","GOLDEN RETRIEVERS","CHESAPEAKE BAY RETRIEVERS","DALMATIANS","WIRE FOX TERRIERS","GERMAN SHEPHERD DOGS","AMERICAN STAFFORDSHIRE TERRIERS","BRITTANYS","WEST HIGHLAND WHITE TERRIERS","CHINESE SHAR-PEI","BELGIAN MALINOIS","MINIATURE PINSCHERS","FLAT-COATED RETRIEVERS","BULLMASTIFFS","CANE CORSO","BOXERS","SHIBA INU","DOGUES DE BORDEAUX","BOSTON TERRIERS","POODLES","SCOTTISH TERRIERS","NORWICH TERRIERS","STANDARD SCHNAUZERS","AFGHAN HOUNDS","YORKSHIRE TERRIERS","MINIATURE SCHNAUZERS","COLLIES","GERMAN WIREHAIRED POINTERS","BULL TERRIERS","BASENJIS","BLOODHOUNDS","BRUSSELS GRIFFONS","POMERANIANS","BORDER TERRIERS","COCKER SPANIELS","BOUVIERS DES FLANDRES","SIBERIAN HUSKIES","SCHIPPERKES","MASTIFFS","OLD ENGLISH SHEEPDOGS","WEIMARANERS","GREAT PYRENEES","AIREDALE TERRIERS","LHASA APSOS","RUSSELL TERRIERS","SAMOYEDS","GIANT SCHNAUZERS","AUSTRALIAN CATTLE DOGS","PEMBROKE WELSH CORGIS","ENGLISH SPRINGER SPANIELS","BORZOIS","BICHONS FRISES","CAIRN TERRIERS","IRISH SETTERS","RHODESIAN RIDGEBACKS","PAPILLONS","WIREHAIRED POINTING GRIFFONS","WHIPPETS","MALTESE","JAPANESE CHIN","CHIHUAHUAS","BORDER COLLIES","SILKY TERRIERS","TREEING WALKER COONHOUNDS","BEAGLES","SHIH TZU","SHETLAND SHEEPDOGS","ENGLISH COCKER SPANIELS","GORDON SETTERS","CHOW CHOWS","AUSTRALIAN SHEPHERDS","DACHSHUNDS","HAVANESE","ST. BERNARDS","CHINESE CRESTED","FRENCH BULLDOGS","PARSON RUSSELL TERRIERS","IRISH WOLFHOUNDS","ENGLISH SETTERS","BASSET HOUNDS","STAFFORDSHIRE BULL TERRIERS","CAVALIER KING CHARLES SPANIELS","TIBETAN TERRIERS","PEKINGESE","DOBERMAN PINSCHERS","BERNESE MOUNTAIN DOGS","NEWFOUNDLANDS","BULLDOGS","SOFT COATED WHEATEN TERRIERS","ALASKAN MALAMUTES","PORTUGUESE WATER DOGS","ITALIAN GREYHOUNDS","GREATER SWISS MOUNTAIN DOGS","GREAT DANES","ROTTWEILERS","GERMAN SHORTHAIRED POINTERS","PUGS"].iter().copied().collect();
-    let cats: Set = ["ORIENTAL LONGHAIR","SAVANNAH","HIMALAYAN-COLORPOINT PERSIAN","AMERICAN BOBTAIL","ABYSSINIAN","EXOTIC SHORTHAIR","ORIENTAL BICOLOR","BRAZILIAN SHORTHAIR","COLORPOINT SHORTHAIR","DWELF","EUROPEAN SHORTHAIR","HIGHLANDER","BENGAL","MANX","SIAMESE","TONKINESE","RUSSIAN BLUE","RAGAMUFFIN","CHARTREUX","BOMBAY","AMERICAN SHORTHAIR","BIRMAN","NORWEGIAN FOREST CAT","PETERBALD","CALIFORNIA SPANGLED CAT","ARABIAN MAU","HAVANA BROWN","KURILIAN BOBTAIL","DONSKOY OR DON SPHYNX","PIXIE-BOB","DEVON REX","DRAGON LI","SERENGETI CAT","AMERICAN CURL","UKRAINIAN LEVKOY","SINGAPURA","GERMAN REX","TOYGER","TURKISH VAN","KHAO MANEE","CHEETOH","BRITISH SHORTHAIR","NAPOLEON","KORN JA","MAINE COON","RUSSIAN BLACK","AUSTRALIAN MIST","SWEDISH FOREST CAT","PERSIAN","YORK CHOCOLATE CAT","CORNISH REX","BAMBINO","JAVANESE","LAPERM","AEGEAN CAT","AMERICAN WIREHAIR","MUNCHKIN","ORIENTAL SHORTHAIR","SAM SAWET","CYPRUS CAT","BURMILLA","SELKIRK REX","TURKISH ANGORA","BALINESE","MINSKIN","SERRADE PETIT","BURMESE","OCICAT","OJOS AZULES","RAGDOLL","SPHYNX","CHAUSIE","NEBELUNG","CHANTILLY","OREGON REX","ASIAN","AMERICAN POLYDACTYL","TIFFANY","BRITISH LONGHAIR","ASIAN SEMI-LONGHAIR","SOKOKE","JAPANESE BOBTAIL","SIBERIAN","EGYPTIAN MAU","THAI","KORAT","MEKONG BOBTAIL","CYMRIC","SOMALI","SNOWSHOE","SCOTTISH FOLD"].iter().copied().collect();
-    println!("{}", find_regex(&mut dogs, &cats));
-    let mut movies: Set = ["ETERNAL SUNSHINE OF THE SPOTLESS MIND","DOUBLE INDEMNITY","TOUCH OF EVIL","MESHES OF THE AFTERNOON","HEAVEN'S GATE","THE RIGHT STUFF","APOCALYPSE NOW","25TH HOUR","THE TREE OF LIFE","KILLER OF SHEEP","THELMA & LOUISE","GONE WITH THE WIND","THE NIGHT OF THE HUNTER","ACE IN THE HOLE","VERTIGO","BARRY LYNDON","CRIMES AND MISDEMEANORS","THE BAND WAGON","CASABLANCA","THE SHOP AROUND THE CORNER","THE GOLD RUSH","THE WIZARD OF OZ","MCCABE & MRS MILLER","NOTORIOUS","NORTH BY NORTHWEST","PULP FICTION","RIO BRAVO","DR STRANGELOVE","IT'S A WONDERFUL LIFE","12 YEARS A SLAVE","THE WILD BUNCH","THE LADY EVE","IMITATION OF LIFE","THE MAGNIFICENT AMBERSONS","SCHINDLER'S LIST","THE DARK KNIGHT","A PLACE IN THE SUN","MODERN TIMES","JOHNNY GUITAR","NETWORK","HIS GIRL FRIDAY","CITY LIGHTS","MULHOLLAND DRIVE","JAWS","THE SHINING","MEAN STREETS","SUNSET BOULEVARD","KOYAANISQATSI","RAIDERS OF THE LOST ARK","THE SEARCHERS","CHINATOWN","BLUE VELVET","THE BIRTH OF A NATION","ANNIE HALL","PSYCHO","SOME LIKE IT HOT","DAYS OF HEAVEN","TAXI DRIVER","WEST SIDE STORY","GOODFELLAS","LETTER FROM AN UNKNOWN WOMAN","THE CONVERSATION","MEET ME IN ST LOUIS","A WOMAN UNDER THE INFLUENCE","CITIZEN KANE","THE BEST YEARS OF OUR LIVES","ONE FLEW OVER THE CUCKOO'S NEST","STAR WARS","ET: THE EXTRA-TERRESTRIAL","BRINGING UP BABY","SUNRISE","THE SHANGHAI GESTURE","DELIVERANCE","STAGECOACH","THE LION KING","NASHVILLE","FORREST GUMP","DUCK SOUP","THE GODFATHER PART II","THE EMPIRE STRIKES BACK","EYES WIDE SHUT","CLOSE ENCOUNTERS OF THE THIRD KIND","THE MAN WHO SHOT LIBERTY VALANCE","GREED","NIGHT OF THE LIVING DEAD","LOVE STREAMS","GREY GARDENS","2001: A SPACE ODYSSEY","GROUNDHOG DAY","IN A LONELY PLACE","MARNIE","DO THE RIGHT THING","THE GRADUATE","THE APARTMENT","RED RIVER","RAGING BULL","SINGIN' IN THE RAIN","BACK TO THE FUTURE","THE GODFATHER","SHERLOCK JR"].iter().copied().collect();
-    let tv: Set = ["THE HONEYMOONERS","THE SUPER BOWL","PEE WEE'S PLAYHOUSE","THE WIRE","LEAVE IT TO BEAVER","THE CBS EVENING NEWS WITH WALTER CRONKITE","TWIN PEAKS","ST ELSEWHERE","DRAGNET","SOAP","THE ED SULLIVAN SHOW","HOMICIDE: LIFE ON THE STREET","THE BOB NEWHART SHOW","MOONLIGHTING","THE LARRY SANDERS SHOW","SECOND CITY TELEVISION","GUNSMOKE","HILL STREET BLUES","THE SINGING DETECTIVE","ALFRED HITCHCOCK PRESENTS","THE BEAVIS AND BUTT-HEAD SHOW","THE MONKEES","TAXI","THE DICK VAN DYKE SHOW","SOUTH PARK","ALL IN THE FAMILY","AN AMERICAN FAMILY","THE OFFICE","THE ERNIE KOVACS SHOW","SESAME STREET","SEE IT NOW","STAR TREK","THE FRENCH CHEF","MY SO-CALLED LIFE","WISEGUY","THE REAL WORLD","SURVIVOR","THE SOPRANOS","GILMORE GIRLS","THE X-FILES","THE DAY AFTER","FREAKS AND GEEKS","ARRESTED DEVELOPMENT","I, CLAUDIUS","SIX FEET UNDER","THE OPRAH WINFREY SHOW","WKRP IN CINCINNATI","I LOVE LUCY","THE ABBOTT AND COSTELLO SHOW","FRIENDS","MASH","LATE NIGHT WITH DAVID LETTERMAN","THE PRISONER","BUFFALO BILL","MONTY PYTHON'S FLYING CIRCUS","ROSEANNE","PLAYHOUSE 90","SPORTSCENTER","PRIME SUSPECT","THE GEORGE BURNS AND GRACIE ALLEN SHOW","MARRIED WITH CHILDREN","ROCKY AND HIS FRIENDS","GENERAL HOSPITAL","BUFFY THE VAMPIRE SLAYER","SEX AND THE CITY","BATTLESTAR GALACTICA","60 MINUTES","SPONGEBOB SQUAREPANTS","AMERICAN IDOL","THE PRICE IS RIGHT","SANFORD AND SON","THE SIMPSONS","WHAT'S MY LINE","LOST","THE COSBY SHOW","THE WEST WING","DEADWOOD","MARY HARTMAN, MARY HARTMAN","THE MARY TYLER MOORE SHOW","MYSTERY SCIENCE THEATER 3000","ABC'S WIDE WORLD OF SPORTS","THE SHIELD","24","ROOTS","THE TWILIGHT ZONE","SEINFELD","SATURDAY NIGHT LIVE","DALLAS","A CHARLIE BROWN CHRISTMAS","THE CAROL BURNETT SHOW","THE DAILY SHOW","KING OF THE HILL","FELICITY","BRIDESHEAD REVISITED","CHEERS","THE ODD COUPLE","THE TONIGHT SHOW STARRING JOHNNY CARSON"].iter().copied().collect();
-    println!("{}", find_regex(&mut movies, &tv));
-    let mut stars: Set = ["WILLIAM HOLDEN","LAURENCE OLIVIER","FRED ASTAIRE","JOHN WAYNE","JUDY GARLAND","BARBARA STANWYCK","AVA GARDNER","GRACE KELLY","EDWARD G. ROBINSON","ROBERT MITCHUM","MARY PICKFORD","SHIRLEY TEMPLE","CAROLE LOMBARD","KIRK DOUGLAS","CLAUDETTE COLBERT","SOPHIA LOREN","JAMES CAGNEY","MARLENE DIETRICH","CLARK GABLE","JEAN HARLOW","SIDNEY POITIER","MARX BROTHERS","INGRID BERGMAN","GREGORY PECK","HUMPHREY BOGART","HENRY FONDA","AUDREY HEPBURN","JAMES STEWART","BUSTER KEATON","ORSON WELLES","MAE WEST","VIVIEN LEIGH","GRETA GARBO","LAUREN BACALL","JAMES DEAN","CARY GRANT","SPENCER TRACY","JOAN CRAWFORD","BURT LANCASTER","ELIZABETH TAYLOR","GINGER ROGERS","BETTE DAVIS","CHARLIE CHAPLIN","MARLON BRANDO","MARILYN MONROE","GARY COOPER","GENE KELLY","LILLIAN GISH","KATHARINE HEPBURN","RITA HAYWORTH"].iter().copied().collect();
-    let scientists: Set = ["ALAN GUTH","ANDREW KNOLL","MARGARET GELLER","C NUSSLEIN-VOLHARD","MILDRED DRESSELHAUS","ROBERT MARKS II","DENNIS BRAY","LENE VESTERGAARD HAU","EDWARD WILSON","ALAIN ASPECT","TIMOTHY BERNERS-LEE","JOHN TYLER BONNER","JANE GOODALL","CHARLES KAO","JACK SZOSTAK","SEIJI OGAWA","SYDNEY BRENNER","LEROY HOOD","JEAN FRECHET","KARY MULLIS","ERIC KANDEL","ANTHONY FIRE","GORDON MOORE","HAROLD VARMUS","CHARLES TOWNES","GERALD M EDELMAN","JAMES WATSON","EDWARD WITTEN","ANTHONY FAUCI","JAMES TOUR","ROGER PENROSE","HENRY F SCHAEFER III","PETER HIGGS","STEVEN WEINBERG","LUC MONTAGNIER","CRAIG MELLO","ALLEN BARD","GEORGE WHITESIDES","DAVID BALTIMORE","PIERRE CHAMBON","STEPHEN HAWKING","MARTIN KARPLUS","STANLEY PRUSINER","DONALD KNUTH","CRAIG VENTER","SHINYA YAMANAKA","THOMAS SUDHOF","JEREMIAH OSTRIKER","RONALD EVANS","SIMON CONWAY MORRIS"].iter().copied().collect();
-    println!("{}", find_regex(&mut stars, &scientists));
+fn bench(mode: Mode){
+    let mut winners: Set = data::WINNERS.iter().copied().collect();
+    let losers: Set = data::LOSERS.iter().copied().collect();
+    run(mode, "winners", &mut winners, &losers);
+    let mut elected: Set = data::ELECTED.iter().copied().collect();
+    let defeated: Set = data::DEFEATED.iter().copied().collect();
+    if let Mode::Weighted | Mode::Tolerant = mode { run(mode, "elected", &mut elected, &defeated) }
+    let mut boys: Set = data::BOYS.iter().copied().collect();
+    let girls: Set = data::GIRLS.iter().copied().collect();
+    run(mode, "boys", &mut boys, &girls);
+    let mut pharma: Set = data::PHARMA.iter().copied().collect();
+    let cities: Set = data::CITIES.iter().copied().collect();
+    run(mode, "pharma", &mut pharma, &cities);
+    let mut foo: Set = data::FOO.iter().copied().collect();
+    let bar: Set = data::BAR.iter().copied().collect();
+    run(mode, "foo", &mut foo, &bar);
+    let mut nouns: Set = data::NOUNS.iter().copied().collect();
+    let adverbs: Set = data::ADVERBS.iter().copied().collect();
+    run(mode, "nouns", &mut nouns, &adverbs);
+    let verbs: Set = data::VERBS.iter().copied().collect();
+    if let Mode::Classes = mode { classes("words", &[("nouns", &nouns), ("adverbs", &adverbs), ("verbs", &verbs)]) }
+    let mut randoms: Set = data::RANDOMS.iter().copied().collect();
+    let builtins: Set = data::BUILTINS.iter().copied().collect();
+    run(mode, "randoms", &mut randoms, &builtins);
+    let mut starwars: Set = data::STARWARS.iter().copied().collect();
+    let startrek: Set = data::STARTREK.iter().copied().collect();
+    run(mode, "starwars", &mut starwars, &startrek);
+    let mut dogs: Set = data::DOGS.iter().copied().collect();
+    let cats: Set = data::CATS.iter().copied().collect();
+    run(mode, "dogs", &mut dogs, &cats);
+    let mut movies: Set = data::MOVIES.iter().copied().collect();
+    let tv: Set = data::TV.iter().copied().collect();
+    run(mode, "movies", &mut movies, &tv);
+    let mut stars: Set = data::STARS.iter().copied().collect();
+    let scientists: Set = data::SCIENTISTS.iter().copied().collect();
+    run(mode, "stars", &mut stars, &scientists);
     }
+
+#[rustfmt::skip]
+mod data {
+pub const WINNERS: &[&str] = &["bush","clinton","monroe","madison","hayes","kennedy","reagan","jefferson","mckinley","taft","wilson","harding","jackson","garfield","truman","van-buren","polk","johnson","roosevelt","carter","cleveland","washington","grant","coolidge","nixon","eisenhower","obama","lincoln","adams","hoover","taylor","harrison","pierce","buchanan"];
+pub const ELECTED: &[&str] = &["bush*3","clinton*2","monroe*2","madison*2","hayes","kennedy","reagan*2","jefferson*2","mckinley*2","taft","wilson*2","harding","jackson*2","garfield","truman","van-buren","polk","johnson","roosevelt*5","carter","cleveland*2","washington","grant*2","coolidge","nixon*2","eisenhower*2","obama*2","lincoln*2","adams*2","hoover","taylor","harrison*2","pierce","buchanan"];
+pub const LOSERS: &[&str] = &["tilden","greeley","dukakis","hughes","smith","landon","fremont","scott","ford","pinckney","gore","king","humphrey","cass","mcclellan","bryan","mcgovern","davis","mccain","clay","cox","dewey","parker","wilkie","stevenson","romney","blaine","seymour","hancock","breckinridge","kerry","goldwater","dole","mondale"];
+pub const DEFEATED: &[&str] = &["tilden","greeley","dukakis","hughes","smith","landon","fremont","scott","ford","pinckney*2","gore","king","humphrey","cass","mcclellan","bryan*3","mcgovern","davis","mccain","clay*2","cox","dewey*2","parker","wilkie","stevenson*2","romney","blaine","seymour","hancock","breckinridge","kerry","goldwater","dole","mondale"];
+pub const BOYS: &[&str] = &["ethan","jayden","alexander","noah","liam","jacob","mason","aiden","michael","william"];
+pub const GIRLS: &[&str] = &["madison","isabella","elizabeth","olivia","emily","emma","ava","mia","abigail","sophia"];
+pub const PHARMA: &[&str] = &["singulair","epogen","ablify","advair","nexium","seroquel","crestor","actos","plavix","lipitor"];
+pub const CITIES: &[&str] = &["capetown","riga","shanghai","vancouver","auckland","paris","chicago","trinidad","adelaide","zurich"];
+pub const FOO: &[&str] = &["padfoot","foolery","foothot","fooster","foolish","jawfoot","prefool","dogfoot","catfoot","afoot","unfool","fanfoot","foody","nonfood","footle","footway","mafoo","sfoot","footage","hotfoot","footpad"];
+pub const BAR: &[&str] = &["unfold","crooked","manlike","palazzi","sixfold","Silipan","altared","forest","tarrock","marly","folksy","chandoo","crenel","Iberic","Aymoro","Atlas","Ormazd","Mahran","fardo","hebamic","idgah"];
+pub const NOUNS: &[&str] = &["air","hour","school","time","program","health","city","house","world","case","guy","hand","father","education","country","friend","eye","morning","party","kind","game","member","lot","company","month","issue","side","information","business","book","number","work","child","group","problem","history","place","back","line","level","year","person","job","team","day","president","family","moment","service","body","result","question","government","story","teacher","research","people","law","force","art","week","parent","idea","kid","room","home","water","thing","mother","end","night","reason","community","study","fact","life","change","door","area","others","point","man","war","woman","way","right","minute","name","girl","system","car","money","word","office","power","student","state","head","face","part"];
+pub const ADVERBS: &[&str] = &["never","in","especially","little","quickly","recently","always","pretty","out","here","ago","today","directly","far","where","tonight","again","often","however","only","maybe","enough","just","as","that","why","well","least","close","more","soon","on","fast","away","up","perhaps","course","finally","simply","all","before","to","how","sometimes","almost","then","probably","exactly","once","long","now","usually","down","suddenly","forward","rather","yet","hard","ok","clearly","already","much","off","also","better","else","when","early","even","quite","of","together","certainly","less","over","around","still","alone","thus","eventually","ahead","very","instead","indeed","most","best","ever","later","particularly","nearly","either","there","both","about","really","actually","no","so"];
+pub const VERBS: &[&str] = &["wait","ask","hear","make","tell","look","draw","understand","explain","drive","cancel","fill","type","pay","fit","give","leave","turn","run","know","fix","do","stand","on","shut","wake","sit","clean","lose","write","swim","spend","bring","eat","forget","start","rain","drink","hurt","have","sing","reply","off","find","translate","able","close","take","cut","begin","learn","go","use","see","live","sign","dance","buy","break","borrow","spell","up","listen","believe","open","count","want","comb","speak","worry","watch","travel","fly","think","complain","sell","organise","teach","send","fall","cough","talk","read","can","try","finish","be","put","need","play","say","succeed","sleep","smoke"];
+pub const RANDOMS: &[&str] = &["setstate","_e","_Sequence","_inst","_os","weibullvariate","_sqrt","getrandbits","_bisect","_pi","LOG4","_urandom","__name__","_ceil","_sha512","_warn","normalvariate","vonmisesvariate","_MethodType","seed","randrange","__package__","SystemRandom","randint","choice","_test","shuffle","getstate","__all__","sample","TWOPI","_BuiltinMethodType","Random","__builtins__","_Set","_test_generator","paretovariate","__file__","lognormvariate","_sin","betavariate","SG_MAGICCONST","__loader__","_cos","RECIP_BPF","uniform","gammavariate","expovariate","gauss","_random","triangular","_exp","__cached__","_acos","_log","BPF","__doc__","__spec__","choices","NV_MAGICCONST","random","_itertools"];
+pub const BUILTINS: &[&str] = &["help","LookupError","IndexError","PendingDeprecationWarning","IOError","globals","NameError","ConnectionError","OSError","ProcessLookupError","bytes","UnicodeError","ResourceWarning","ImportWarning","BytesWarning","KeyError","quit","KeyboardInterrupt","dir","credits","breakpoint","len","tuple","BufferError","id","compile","next","BlockingIOError","ConnectionResetError","GeneratorExit","copyright","memoryview","sorted","min","AssertionError","SystemError","StopAsyncIteration","bytearray","enumerate","max","type","callable","any","ord","range","exec","ArithmeticError","open","bin","__import__","ValueError","getattr","oct","ZeroDivisionError","hash","PermissionError","all","divmod","ReferenceError","RuntimeError","EOFError","sum","RecursionError","pow","float","locals","reversed","slice","UnicodeDecodeError","SyntaxWarning","ChildProcessError","IsADirectoryError","DeprecationWarning","abs","classmethod","isinstance","hex","UnicodeWarning","False","chr","issubclass","frozenset","str","FutureWarning","hasattr","print","EnvironmentError","IndentationError","TypeError","ConnectionRefusedError","set","FloatingPointError","round","AttributeError","TabError","BaseException","ModuleNotFoundError","dict","super","Exception","NotImplemented","Ellipsis","filter","property","UnboundLocalError","ConnectionAbortedError","eval","format","zip","RuntimeWarning","Warning","NotADirectoryError","SyntaxError","UnicodeTranslateError","OverflowError","None","object","setattr","UnicodeEncodeError","True","input","list","UserWarning","map","license","__debug__","NotImplementedError","iter","vars","SystemExit","BrokenPipeError","ascii","FileExistsError","InterruptedError","bool","StopIteration","int","repr","ImportError","delattr","__build_class__","FileNotFoundError","staticmethod","MemoryError","complex","exit","TimeoutError"];
+pub const STARWARS: &[&str] = &["ATTACK OF THE CLONES","THE PHANTOM MENACE","REVENGE OF THE SITH","THE EMPIRE STRIKES BACK","A NEW HOPE","RETURN OF THE JEDI"];
+pub const STARTREK: &[&str] = &["GENERATIONS","THE WRATH OF KHAN","THE SEARCH FOR SPOCK","NEMESIS","THE UNDISCOVERED COUNTRY","THE FINAL FRONTIER","INSURRECTION","FIRST CONTACT","THE VOYAGE HOME"];
+pub const DOGS: &[&str] = &["'LABRADOR RETRIEVERS","CARDIGAN WELSH CORGIS","AKITAS","VIZSLAS","GOLDEN RETRIEVERS","CHESAPEAKE BAY RETRIEVERS","DALMATIANS","WIRE FOX TERRIERS","GERMAN SHEPHERD DOGS","AMERICAN STAFFORDSHIRE TERRIERS","BRITTANYS","WEST HIGHLAND WHITE TERRIERS","CHINESE SHAR-PEI","BELGIAN MALINOIS","MINIATURE PINSCHERS","FLAT-COATED RETRIEVERS","BULLMASTIFFS","CANE CORSO","BOXERS","SHIBA INU","DOGUES DE BORDEAUX","BOSTON TERRIERS","POODLES","SCOTTISH TERRIERS","NORWICH TERRIERS","STANDARD SCHNAUZERS","AFGHAN HOUNDS","YORKSHIRE TERRIERS","MINIATURE SCHNAUZERS","COLLIES","GERMAN WIREHAIRED POINTERS","BULL TERRIERS","BASENJIS","BLOODHOUNDS","BRUSSELS GRIFFONS","POMERANIANS","BORDER TERRIERS","COCKER SPANIELS","BOUVIERS DES FLANDRES","SIBERIAN HUSKIES","SCHIPPERKES","MASTIFFS","OLD ENGLISH SHEEPDOGS","WEIMARANERS","GREAT PYRENEES","AIREDALE TERRIERS","LHASA APSOS","RUSSELL TERRIERS","SAMOYEDS","GIANT SCHNAUZERS","AUSTRALIAN CATTLE DOGS","PEMBROKE WELSH CORGIS","ENGLISH SPRINGER SPANIELS","BORZOIS","BICHONS FRISES","CAIRN TERRIERS","IRISH SETTERS","RHODESIAN RIDGEBACKS","PAPILLONS","WIREHAIRED POINTING GRIFFONS","WHIPPETS","MALTESE","JAPANESE CHIN","CHIHUAHUAS","BORDER COLLIES","SILKY TERRIERS","TREEING WALKER COONHOUNDS","BEAGLES","SHIH TZU","SHETLAND SHEEPDOGS","ENGLISH COCKER SPANIELS","GORDON SETTERS","CHOW CHOWS","AUSTRALIAN SHEPHERDS","DACHSHUNDS","HAVANESE","ST. BERNARDS","CHINESE CRESTED","FRENCH BULLDOGS","PARSON RUSSELL TERRIERS","IRISH WOLFHOUNDS","ENGLISH SETTERS","BASSET HOUNDS","STAFFORDSHIRE BULL TERRIERS","CAVALIER KING CHARLES SPANIELS","TIBETAN TERRIERS","PEKINGESE","DOBERMAN PINSCHERS","BERNESE MOUNTAIN DOGS","NEWFOUNDLANDS","BULLDOGS","SOFT COATED WHEATEN TERRIERS","ALASKAN MALAMUTES","PORTUGUESE WATER DOGS","ITALIAN GREYHOUNDS","GREATER SWISS MOUNTAIN DOGS","GREAT DANES","ROTTWEILERS","GERMAN SHORTHAIRED POINTERS","PUGS"];
+pub const CATS: &[&str] = &["ORIENTAL LONGHAIR","SAVANNAH","HIMALAYAN-COLORPOINT PERSIAN","AMERICAN BOBTAIL","ABYSSINIAN","EXOTIC SHORTHAIR","ORIENTAL BICOLOR","BRAZILIAN SHORTHAIR","COLORPOINT SHORTHAIR","DWELF","EUROPEAN SHORTHAIR","HIGHLANDER","BENGAL","MANX","SIAMESE","TONKINESE","RUSSIAN BLUE","RAGAMUFFIN","CHARTREUX","BOMBAY","AMERICAN SHORTHAIR","BIRMAN","NORWEGIAN FOREST CAT","PETERBALD","CALIFORNIA SPANGLED CAT","ARABIAN MAU","HAVANA BROWN","KURILIAN BOBTAIL","DONSKOY OR DON SPHYNX","PIXIE-BOB","DEVON REX","DRAGON LI","SERENGETI CAT","AMERICAN CURL","UKRAINIAN LEVKOY","SINGAPURA","GERMAN REX","TOYGER","TURKISH VAN","KHAO MANEE","CHEETOH","BRITISH SHORTHAIR","NAPOLEON","KORN JA","MAINE COON","RUSSIAN BLACK","AUSTRALIAN MIST","SWEDISH FOREST CAT","PERSIAN","YORK CHOCOLATE CAT","CORNISH REX","BAMBINO","JAVANESE","LAPERM","AEGEAN CAT","AMERICAN WIREHAIR","MUNCHKIN","ORIENTAL SHORTHAIR","SAM SAWET","CYPRUS CAT","BURMILLA","SELKIRK REX","TURKISH ANGORA","BALINESE","MINSKIN","SERRADE PETIT","BURMESE","OCICAT","OJOS AZULES","RAGDOLL","SPHYNX","CHAUSIE","NEBELUNG","CHANTILLY","OREGON REX","ASIAN","AMERICAN POLYDACTYL","TIFFANY","BRITISH LONGHAIR","ASIAN SEMI-LONGHAIR","SOKOKE","JAPANESE BOBTAIL","SIBERIAN","EGYPTIAN MAU","THAI","KORAT","MEKONG BOBTAIL","CYMRIC","SOMALI","SNOWSHOE","SCOTTISH FOLD"];
+pub const MOVIES: &[&str] = &["ETERNAL SUNSHINE OF THE SPOTLESS MIND","DOUBLE INDEMNITY","TOUCH OF EVIL","MESHES OF THE AFTERNOON","HEAVEN'S GATE","THE RIGHT STUFF","APOCALYPSE NOW","25TH HOUR","THE TREE OF LIFE","KILLER OF SHEEP","THELMA & LOUISE","GONE WITH THE WIND","THE NIGHT OF THE HUNTER","ACE IN THE HOLE","VERTIGO","BARRY LYNDON","CRIMES AND MISDEMEANORS","THE BAND WAGON","CASABLANCA","THE SHOP AROUND THE CORNER","THE GOLD RUSH","THE WIZARD OF OZ","MCCABE & MRS MILLER","NOTORIOUS","NORTH BY NORTHWEST","PULP FICTION","RIO BRAVO","DR STRANGELOVE","IT'S A WONDERFUL LIFE","12 YEARS A SLAVE","THE WILD BUNCH","THE LADY EVE","IMITATION OF LIFE","THE MAGNIFICENT AMBERSONS","SCHINDLER'S LIST","THE DARK KNIGHT","A PLACE IN THE SUN","MODERN TIMES","JOHNNY GUITAR","NETWORK","HIS GIRL FRIDAY","CITY LIGHTS","MULHOLLAND DRIVE","JAWS","THE SHINING","MEAN STREETS","SUNSET BOULEVARD","KOYAANISQATSI","RAIDERS OF THE LOST ARK","THE SEARCHERS","CHINATOWN","BLUE VELVET","THE BIRTH OF A NATION","ANNIE HALL","PSYCHO","SOME LIKE IT HOT","DAYS OF HEAVEN","TAXI DRIVER","WEST SIDE STORY","GOODFELLAS","LETTER FROM AN UNKNOWN WOMAN","THE CONVERSATION","MEET ME IN ST LOUIS","A WOMAN UNDER THE INFLUENCE","CITIZEN KANE","THE BEST YEARS OF OUR LIVES","ONE FLEW OVER THE CUCKOO'S NEST","STAR WARS","ET: THE EXTRA-TERRESTRIAL","BRINGING UP BABY","SUNRISE","THE SHANGHAI GESTURE","DELIVERANCE","STAGECOACH","THE LION KING","NASHVILLE","FORREST GUMP","DUCK SOUP","THE GODFATHER PART II","THE EMPIRE STRIKES BACK","EYES WIDE SHUT","CLOSE ENCOUNTERS OF THE THIRD KIND","THE MAN WHO SHOT LIBERTY VALANCE","GREED","NIGHT OF THE LIVING DEAD","LOVE STREAMS","GREY GARDENS","2001: A SPACE ODYSSEY","GROUNDHOG DAY","IN A LONELY PLACE","MARNIE","DO THE RIGHT THING","THE GRADUATE","THE APARTMENT","RED RIVER","RAGING BULL","SINGIN' IN THE RAIN","BACK TO THE FUTURE","THE GODFATHER","SHERLOCK JR"];
+pub const TV: &[&str] = &["THE HONEYMOONERS","THE SUPER BOWL","PEE WEE'S PLAYHOUSE","THE WIRE","LEAVE IT TO BEAVER","THE CBS EVENING NEWS WITH WALTER CRONKITE","TWIN PEAKS","ST ELSEWHERE","DRAGNET","SOAP","THE ED SULLIVAN SHOW","HOMICIDE: LIFE ON THE STREET","THE BOB NEWHART SHOW","MOONLIGHTING","THE LARRY SANDERS SHOW","SECOND CITY TELEVISION","GUNSMOKE","HILL STREET BLUES","THE SINGING DETECTIVE","ALFRED HITCHCOCK PRESENTS","THE BEAVIS AND BUTT-HEAD SHOW","THE MONKEES","TAXI","THE DICK VAN DYKE SHOW","SOUTH PARK","ALL IN THE FAMILY","AN AMERICAN FAMILY","THE OFFICE","THE ERNIE KOVACS SHOW","SESAME STREET","SEE IT NOW","STAR TREK","THE FRENCH CHEF","MY SO-CALLED LIFE","WISEGUY","THE REAL WORLD","SURVIVOR","THE SOPRANOS","GILMORE GIRLS","THE X-FILES","THE DAY AFTER","FREAKS AND GEEKS","ARRESTED DEVELOPMENT","I, CLAUDIUS","SIX FEET UNDER","THE OPRAH WINFREY SHOW","WKRP IN CINCINNATI","I LOVE LUCY","THE ABBOTT AND COSTELLO SHOW","FRIENDS","MASH","LATE NIGHT WITH DAVID LETTERMAN","THE PRISONER","BUFFALO BILL","MONTY PYTHON'S FLYING CIRCUS","ROSEANNE","PLAYHOUSE 90","SPORTSCENTER","PRIME SUSPECT","THE GEORGE BURNS AND GRACIE ALLEN SHOW","MARRIED WITH CHILDREN","ROCKY AND HIS FRIENDS","GENERAL HOSPITAL","BUFFY THE VAMPIRE SLAYER","SEX AND THE CITY","BATTLESTAR GALACTICA","60 MINUTES","SPONGEBOB SQUAREPANTS","AMERICAN IDOL","THE PRICE IS RIGHT","SANFORD AND SON","THE SIMPSONS","WHAT'S MY LINE","LOST","THE COSBY SHOW","THE WEST WING","DEADWOOD","MARY HARTMAN, MARY HARTMAN","THE MARY TYLER MOORE SHOW","MYSTERY SCIENCE THEATER 3000","ABC'S WIDE WORLD OF SPORTS","THE SHIELD","24","ROOTS","THE TWILIGHT ZONE","SEINFELD","SATURDAY NIGHT LIVE","DALLAS","A CHARLIE BROWN CHRISTMAS","THE CAROL BURNETT SHOW","THE DAILY SHOW","KING OF THE HILL","FELICITY","BRIDESHEAD REVISITED","CHEERS","THE ODD COUPLE","THE TONIGHT SHOW STARRING JOHNNY CARSON"];
+pub const STARS: &[&str] = &["WILLIAM HOLDEN","LAURENCE OLIVIER","FRED ASTAIRE","JOHN WAYNE","JUDY GARLAND","BARBARA STANWYCK","AVA GARDNER","GRACE KELLY","EDWARD G. ROBINSON","ROBERT MITCHUM","MARY PICKFORD","SHIRLEY TEMPLE","CAROLE LOMBARD","KIRK DOUGLAS","CLAUDETTE COLBERT","SOPHIA LOREN","JAMES CAGNEY","MARLENE DIETRICH","CLARK GABLE","JEAN HARLOW","SIDNEY POITIER","MARX BROTHERS","INGRID BERGMAN","GREGORY PECK","HUMPHREY BOGART","HENRY FONDA","AUDREY HEPBURN","JAMES STEWART","BUSTER KEATON","ORSON WELLES","MAE WEST","VIVIEN LEIGH","GRETA GARBO","LAUREN BACALL","JAMES DEAN","CARY GRANT","SPENCER TRACY","JOAN CRAWFORD","BURT LANCASTER","ELIZABETH TAYLOR","GINGER ROGERS","BETTE DAVIS","CHARLIE CHAPLIN","MARLON BRANDO","MARILYN MONROE","GARY COOPER","GENE KELLY","LILLIAN GISH","KATHARINE HEPBURN","RITA HAYWORTH"];
+pub const SCIENTISTS: &[&str] = &["ALAN GUTH","ANDREW KNOLL","MARGARET GELLER","C NUSSLEIN-VOLHARD","MILDRED DRESSELHAUS","ROBERT MARKS II","DENNIS BRAY","LENE VESTERGAARD HAU","EDWARD WILSON","ALAIN ASPECT","TIMOTHY BERNERS-LEE","JOHN TYLER BONNER","JANE GOODALL","CHARLES KAO","JACK SZOSTAK","SEIJI OGAWA","SYDNEY BRENNER","LEROY HOOD","JEAN FRECHET","KARY MULLIS","ERIC KANDEL","ANTHONY FIRE","GORDON MOORE","HAROLD VARMUS","CHARLES TOWNES","GERALD M EDELMAN","JAMES WATSON","EDWARD WITTEN","ANTHONY FAUCI","JAMES TOUR","ROGER PENROSE","HENRY F SCHAEFER III","PETER HIGGS","STEVEN WEINBERG","LUC MONTAGNIER","CRAIG MELLO","ALLEN BARD","GEORGE WHITESIDES","DAVID BALTIMORE","PIERRE CHAMBON","STEPHEN HAWKING","MARTIN KARPLUS","STANLEY PRUSINER","DONALD KNUTH","CRAIG VENTER","SHINYA YAMANAKA","THOMAS SUDHOF","JEREMIAH OSTRIKER","RONALD EVANS","SIMON CONWAY MORRIS"];
+}
@@ -0,0 +1,132 @@
+use crate::cover::{prune, Bits};
+use crate::h4x_re::Regex;
+use crate::Set;
+use itertools::Itertools;
+
+/// Tidies up a finished alternation, without ever matching a loser or
+/// losing a winner.
+///
+/// Parts the others already cover are dropped, then each part is edited
+/// (chars trimmed, letters dotted, anchors added or dropped) while that
+/// makes it cheaper or lets it match more winners, so more parts can go.
+pub fn optimize(mut parts: Vec<Regex>, winners: &Set, losers: &Set) -> Vec<Regex> {
+    let words = winners.iter().copied().sorted().collect_vec();
+    let hits = |part: &Regex| words.iter().map(|x| part.is_match(x)).collect_vec();
+
+    loop {
+        drop_redundant(&mut parts, &words);
+        let mut changed = false;
+        for i in 0..parts.len() {
+            // Winners no other part matches, which any replacement must keep
+            let needed = words
+                .iter()
+                .enumerate()
+                .filter(|&(_, word)| {
+                    parts
+                        .iter()
+                        .enumerate()
+                        .all(|(j, part)| j == i || !part.is_match(word))
+                })
+                .map(|(n, _)| n)
+                .collect_vec();
+            let old = hits(&parts[i]);
+            let old_count = old.iter().filter(|&&x| x).count();
+
+            let better = edits(&parts[i].to_string())
+                .into_iter()
+                .map(Regex::new)
+                .filter(|part| losers.iter().all(|loser| !part.is_match(loser)))
+                .filter_map(|part| {
+                    let new = hits(&part);
+                    if !needed.iter().all(|&n| new[n]) {
+                        return None;
+                    }
+                    let count = new.iter().filter(|&&x| x).count();
+                    let superset = old.iter().zip(&new).all(|(&old, &new)| !old || new);
+                    if part.cost() < parts[i].cost()
+                        || (part.cost() == parts[i].cost() && superset && count > old_count)
+                    {
+                        Some((part.cost(), -(count as i64), part))
+                    } else {
+                        None
+                    }
+                })
+                .min_by_key(|(cost, count, _)| (*cost, *count));
+
+            if let Some((_, _, part)) = better {
+                parts[i] = part;
+                changed = true;
+            }
+        }
+        if !changed {
+            return parts;
+        }
+    }
+}
+
+/// Drops parts the others cover, keeping the rest in order
+fn drop_redundant(parts: &mut Vec<Regex>, words: &[&str]) {
+    let hits = parts
+        .iter()
+        .map(|part| {
+            let mut hits = Bits::new(words.len());
+            for (n, word) in words.iter().enumerate() {
+                if part.is_match(word) {
+                    hits.insert(n);
+                }
+            }
+            hits
+        })
+        .collect_vec();
+    let kept = prune(
+        (0..parts.len()).collect(),
+        words.len(),
+        |x| &hits[x],
+        |x| parts[x].cost() + 1,
+    );
+    *parts = kept
+        .into_iter()
+        .sorted()
+        .map(|x| parts[x].clone())
+        .collect();
+}
+
+/// Everything one or two edits away from `part`
+fn edits(part: &str) -> Vec<String> {
+    let once = single_edits(part);
+    let twice = once.iter().flat_map(|x| single_edits(x)).collect_vec();
+    once.into_iter().chain(twice).unique().collect()
+}
+
+fn single_edits(part: &str) -> Vec<String> {
+    let front = part.starts_with('^');
+    let back = part.ends_with('$');
+    let body = &part[front as usize..part.len() - back as usize];
+    let wrap = |body: &str, front: bool, back: bool| {
+        format!(
+            "{}{}{}",
+            if front { "^" } else { "" },
+            body,
+            if back { "$" } else { "" }
+        )
+    };
+
+    let mut out = vec![wrap(body, !front, back), wrap(body, front, !back)];
+    for i in 0..body.len() {
+        if body.len() > 1 {
+            out.push(wrap(
+                &format!("{}{}", &body[..i], &body[i + 1..]),
+                front,
+                back,
+            ));
+        }
+        if body.as_bytes()[i] != b'.' {
+            out.push(wrap(
+                &format!("{}.{}", &body[..i], &body[i + 1..]),
+                front,
+                back,
+            ));
+        }
+    }
+    out
+}
@@ -0,0 +1,117 @@
+use crate::h4x_re::Regex;
+use crate::{greedy, regex_covers, score, Set};
+use itertools::Itertools;
+use std::fmt;
+
+/// Parts `find_regex_with` has to use, and parts it can't.
+///
+/// Forbidden entries are globs over a part as written, with `*` for any
+/// run of chars and `?` for one, so `*..*` bans two dots in a row and `^*$`
+/// bans whole words. Parts never contain either, so a plain part bans just
+/// itself.
+#[derive(Default, Debug)]
+pub struct Pins<'a> {
+    pub pinned: Vec<&'a str>,
+    pub forbidden: Vec<&'a str>,
+}
+
+#[derive(Debug)]
+pub enum Unsolvable<'a> {
+    /// A pinned part that isn't one this engine can run
+    BadPin(String),
+    /// A pinned part matches this loser
+    PinnedLoser(Regex, &'a str),
+    /// Nothing allowed matches these winners
+    Uncovered(Vec<&'a str>),
+    /// No cover has few enough parts, and the best try misses these winners
+    OutOfParts(Vec<&'a str>),
+    /// No cover with few enough parts turned up in time, though there may be
+    /// one
+    NotFound,
+}
+
+impl fmt::Display for Unsolvable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BadPin(part) => write!(f, "can't pin {:?}", part),
+            Self::PinnedLoser(part, loser) => write!(
+                f,
+                "pinned part {} matches loser {}",
+                part.to_string(),
+                loser
+            ),
+            Self::Uncovered(words) => write!(f, "nothing allowed matches {}", words.join(", ")),
+            Self::OutOfParts(words) => write!(f, "too few parts to match {}", words.join(", ")),
+            Self::NotFound => write!(f, "no answer with few enough parts found in time"),
+        }
+    }
+}
+
+/// `find_regex`, starting from the pinned parts and never using a
+/// forbidden one
+pub fn find_regex_with<'a>(
+    winners: &Set<'a>,
+    losers: &Set<'a>,
+    pins: &Pins,
+) -> Result<Vec<Regex>, Unsolvable<'a>> {
+    if let Some(bad) = pins.pinned.iter().find(|x| !valid(x)) {
+        return Err(Unsolvable::BadPin(bad.to_string()));
+    }
+    let pinned = pins
+        .pinned
+        .iter()
+        .map(|x| Regex::new(x.to_string()))
+        .collect_vec();
+    for part in &pinned {
+        if let Some(loser) = losers.iter().sorted().find(|x| part.is_match(x)) {
+            return Err(Unsolvable::PinnedLoser(part.clone(), loser));
+        }
+    }
+
+    let left: Set = winners
+        .iter()
+        .copied()
+        .filter(|x| !pinned.iter().any(|part| part.is_match(x)))
+        .collect();
+    let mut covers = regex_covers(&left, losers);
+    covers.retain(|part, _| {
+        let part = part.to_string();
+        !pins
+            .forbidden
+            .iter()
+            .any(|x| glob(x.as_bytes(), part.as_bytes()))
+    });
+
+    let uncovered = left
+        .iter()
+        .copied()
+        .filter(|x| !covers.values().any(|matched| matched.contains(&x.as_ptr())))
+        .sorted()
+        .collect_vec();
+    if !uncovered.is_empty() {
+        return Err(Unsolvable::Uncovered(uncovered));
+    }
+    Ok(pinned
+        .into_iter()
+        .chain(greedy(covers, &left, &score::Linear(4)))
+        .collect())
+}
+
+/// Whether `part` is something `Regex` can run: more than just anchors,
+/// anchors only at the ends, and no syntax it doesn't have
+fn valid(part: &str) -> bool {
+    let inner = part.strip_prefix('^').unwrap_or(part);
+    let inner = inner.strip_suffix('$').unwrap_or(inner);
+    !inner.is_empty() && !inner.contains(|x| "^$|*+?()[]{}\\".contains(x))
+}
+
+fn glob(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, _) => text.is_empty(),
+        (Some(b'*'), _) => {
+            glob(&pattern[1..], text) || (!text.is_empty() && glob(pattern, &text[1..]))
+        }
+        (Some(_), None) => false,
+        (Some(&x), Some(&y)) => (x == b'?' || x == y) && glob(&pattern[1..], &text[1..]),
+    }
+}
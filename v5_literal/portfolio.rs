@@ -0,0 +1,106 @@
+use crate::cover::{Limits, Table};
+use crate::h4x_re::Regex;
+use crate::{anneal, beam, exact, greedy, lp, regex_covers, score, Set};
+use itertools::Itertools;
+use std::sync::atomic::AtomicUsize;
+use std::thread;
+use std::time::{Duration, Instant};
+
+type Strategy<'a> = (&'static str, Box<dyn Fn() -> Vec<Regex> + Sync + 'a>);
+
+pub struct Raced {
+    pub parts: Vec<Regex>,
+    pub strategy: &'static str,
+}
+
+/// Runs each strategy on its own thread over one shared cover table, and
+/// keeps the shortest answer. Ties go to the strategy listed first.
+///
+/// Every strategy is given the deadline, so stops when `budget` runs out
+/// with the best it has, and the length of the best answer so far, which
+/// restarts and branch and bound prune against.
+pub fn race(winners: &Set, losers: &Set, budget: Duration) -> Raced {
+    let deadline = Instant::now() + budget;
+    let covers = regex_covers(winners, losers);
+    let table = Table::new(&covers, winners);
+    let upper = table.indices(&greedy(covers.clone(), winners, &score::Linear(4)));
+    let best = AtomicUsize::new(table.cost(&upper));
+    let limits = Limits {
+        deadline: Some(deadline),
+        shared: Some(&best),
+        ..Default::default()
+    };
+
+    let table = &table;
+    let upper = &upper;
+    let strategies: Vec<Strategy> = vec![
+        ("greedy", Box::new(|| table.regexes(upper))),
+        (
+            "beam",
+            Box::new(|| table.regexes(&beam::beam(table, 8, &score::Linear(4), limits))),
+        ),
+        (
+            "restart",
+            Box::new(|| {
+                table.regexes(&beam::restarts(
+                    table,
+                    64,
+                    3.0,
+                    0,
+                    &score::Linear(4),
+                    limits,
+                ))
+            }),
+        ),
+        (
+            "lp",
+            Box::new(|| {
+                let relaxed = lp::relax(table, 10_000, limits);
+                table.regexes(&lp::round(table, &relaxed, 64, 0, limits))
+            }),
+        ),
+        (
+            "anneal",
+            Box::new(|| {
+                let schedule = anneal::Schedule {
+                    start: 2.0,
+                    end: 0.05,
+                    steps: 20_000,
+                };
+                anneal::anneal(table, losers, upper, &schedule, 0, limits)
+            }),
+        ),
+        (
+            "exact",
+            Box::new(|| table.regexes(&exact::solve(table, upper, limits).parts)),
+        ),
+    ];
+
+    let results = thread::scope(|scope| {
+        let handles: Vec<_> = strategies
+            .iter()
+            .map(|(name, strategy)| {
+                scope.spawn(move || {
+                    let parts = strategy();
+                    limits.offer(len(&parts));
+                    (*name, parts)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|x| x.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let (strategy, parts) = results
+        .into_iter()
+        .min_by_key(|(_, parts)| len(parts))
+        .unwrap();
+    Raced { parts, strategy }
+}
+
+/// Length of the alternation, as annealing can merge parts not in the table
+fn len(parts: &[Regex]) -> usize {
+    parts.iter().map(|x| x.to_string()).join("|").len()
+}
@@ -0,0 +1,45 @@
+use crate::h4x_re::Regex;
+use crate::{greedy, regex_covers, score, Set};
+use itertools::Itertools;
+
+pub struct Repaired {
+    pub parts: Vec<Regex>,
+    pub added: Vec<Regex>,
+    pub removed: Vec<Regex>,
+}
+
+/// Fixes an old answer for new word lists with as few changes as it can.
+///
+/// Old parts stay if they still match a winner and no loser, and greedy
+/// only runs on the winners none of them match.
+pub fn repair(previous: &str, winners: &Set, losers: &Set) -> Repaired {
+    let (kept, removed): (Vec<_>, Vec<_>) = previous
+        .split('|')
+        .filter(|x| !x.is_empty())
+        .map(|x| Regex::new(x.to_owned()))
+        .partition(|part| {
+            winners.iter().any(|x| part.is_match(x)) && losers.iter().all(|x| !part.is_match(x))
+        });
+    let left: Set = winners
+        .iter()
+        .copied()
+        .filter(|x| !kept.iter().any(|part| part.is_match(x)))
+        .collect();
+    let added = greedy(regex_covers(&left, losers), &left, &score::Linear(4));
+    Repaired {
+        parts: kept.into_iter().chain(added.iter().cloned()).collect(),
+        added,
+        removed,
+    }
+}
+
+impl Repaired {
+    /// Like `diff`, `-part` for each removed and `+part` for each added
+    pub fn diff(&self) -> String {
+        self.removed
+            .iter()
+            .map(|x| format!("-{}", x.to_string()))
+            .chain(self.added.iter().map(|x| format!("+{}", x.to_string())))
+            .join(" ")
+    }
+}
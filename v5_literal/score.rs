@@ -0,0 +1,101 @@
+use crate::h4x_re::Regex;
+use crate::{greedy, Covers, Set};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// How `greedy` ranks a part, given how many uncovered winners it matches.
+///
+/// Must not go up as `hits` goes down, as `greedy` only rescores parts that
+/// were the best last time they were scored.
+pub trait Score: Debug {
+    fn score(&self, part: &Regex, hits: usize) -> f64;
+}
+
+/// A score that can go in a `BinaryHeap`
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Ranked(pub f64);
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// `weight * hits - cost`, the original rule with a weight of 4
+#[derive(Debug, Clone, Copy)]
+pub struct Linear(pub i64);
+
+impl Score for Linear {
+    fn score(&self, part: &Regex, hits: usize) -> f64 {
+        (self.0 * hits as i64 - part.cost() as i64) as f64
+    }
+}
+
+/// `Linear`, costing parts as written for a full-match engine
+#[derive(Debug, Clone, Copy)]
+pub struct FullMatch(pub i64);
+
+impl Score for FullMatch {
+    fn score(&self, part: &Regex, hits: usize) -> f64 {
+        (self.0 * hits as i64 - part.full_cost() as i64) as f64
+    }
+}
+
+/// Winners matched per character
+#[derive(Debug, Clone, Copy)]
+pub struct Ratio;
+
+impl Score for Ratio {
+    fn score(&self, part: &Regex, hits: usize) -> f64 {
+        hits as f64 / part.cost() as f64
+    }
+}
+
+/// `Linear`, but each dot and anchor costs extra, for output people can read
+#[derive(Debug, Clone, Copy)]
+pub struct Readable {
+    pub weight: i64,
+    pub dot: f64,
+    pub anchor: f64,
+}
+
+impl Score for Readable {
+    fn score(&self, part: &Regex, hits: usize) -> f64 {
+        Linear(self.weight).score(part, hits)
+            - self.dot * part.dots() as f64
+            - self.anchor * part.anchors() as f64
+    }
+}
+
+/// Runs greedy with each built in score, and keeps the shortest result
+pub fn tune(covers: &Covers, winners: &Set) -> (Box<dyn Score>, Vec<Regex>) {
+    let mut options: Vec<Box<dyn Score>> = vec![];
+    for weight in 2..=6 {
+        options.push(Box::new(Linear(weight)));
+    }
+    options.push(Box::new(Ratio));
+    for &weight in &[3, 4, 5] {
+        options.push(Box::new(Readable {
+            weight,
+            dot: 0.5,
+            anchor: 0.5,
+        }));
+    }
+
+    options
+        .into_iter()
+        .map(|score| {
+            let parts = greedy(covers.clone(), winners, &*score);
+            (score, parts)
+        })
+        .min_by_key(|(_, parts)| parts.iter().map(|x| x.cost() + 1).sum::<usize>())
+        .unwrap()
+}
@@ -0,0 +1,99 @@
+use crate::cover::{Limits, Table};
+use crate::h4x_re::Regex;
+use crate::pins::Unsolvable;
+use crate::{add_covers, cuts, exact, greedy, score, subparts, Covers, Set};
+use itertools::Itertools;
+use std::time::{Duration, Instant};
+
+/// Limits on what an answer can look like, for engines and people that
+/// can't take everything
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Shape {
+    /// Most parts in the alternation
+    pub max_parts: Option<usize>,
+    /// Longest part, anchors included. Without it, parts are cut from up
+    /// to 4 chars of `^word$` like `subparts`, plus the whole thing.
+    pub max_len: Option<usize>,
+    pub no_anchors: bool,
+    /// Most dots in a part, so `Some(0)` is literal only, as for `grep -F`
+    pub max_dots: Option<usize>,
+}
+
+impl Shape {
+    /// `cuts` of a winner, and longer `subparts` without dots if `max_len`
+    /// allows them, but only the ones that fit
+    fn parts(&self, word: &str) -> impl Iterator<Item = Regex> {
+        let whole = if self.no_anchors {
+            word.to_owned()
+        } else {
+            format!("^{}$", word)
+        };
+        let longer = subparts(whole.clone(), self.max_len.unwrap_or(0))
+            .filter(|x| x.len() > 4)
+            .map(Regex::new);
+        let shape = *self;
+        cuts(whole).chain(longer).filter(move |part| {
+            shape.max_len.is_none_or(|x| part.cost() <= x)
+                && shape.max_dots.is_none_or(|x| part.dots() <= x)
+        })
+    }
+}
+
+/// `find_regex` keeping to `shape`, or the winners it can't cover.
+///
+/// If greedy needs too many parts, branch and bound looks for the shortest
+/// answer with few enough, until `budget` runs out. Only if it finishes
+/// without one is that reported as `OutOfParts`, with the winners missed by
+/// the parts matching the most winners each time.
+pub fn find<'a>(
+    winners: &Set<'a>,
+    losers: &Set<'a>,
+    shape: &Shape,
+    budget: Duration,
+) -> Result<Vec<Regex>, Unsolvable<'a>> {
+    let deadline = Instant::now() + budget;
+    let mut covers = Covers::new();
+    for word in winners {
+        add_covers(&mut covers, shape.parts(word), winners, losers);
+    }
+
+    let uncovered = |parts: &[Regex]| {
+        winners
+            .iter()
+            .copied()
+            .filter(|x| !parts.iter().any(|part| part.is_match(x)))
+            .sorted()
+            .collect_vec()
+    };
+    let everything = covers.keys().cloned().collect_vec();
+    let missed = uncovered(&everything);
+    if !missed.is_empty() {
+        return Err(Unsolvable::Uncovered(missed));
+    }
+
+    let parts = greedy(covers.clone(), winners, &score::Linear(4));
+    let most = match shape.max_parts {
+        Some(most) if parts.len() > most => most,
+        _ => return Ok(parts),
+    };
+    let widest = greedy(covers.clone(), winners, &score::Linear(1000));
+    let table = Table::new(&covers, winners);
+    let upper = if widest.len() <= most {
+        table.indices(&widest)
+    } else {
+        vec![]
+    };
+    let limits = Limits {
+        max_parts: Some(most),
+        deadline: Some(deadline),
+        ..Default::default()
+    };
+    let solved = exact::solve(&table, &upper, limits);
+    if !solved.parts.is_empty() {
+        Ok(table.regexes(&solved.parts))
+    } else if solved.proven {
+        Err(Unsolvable::OutOfParts(uncovered(&widest[..most])))
+    } else {
+        Err(Unsolvable::NotFound)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::cover::Bits;
+use crate::h4x_re::Regex;
+use crate::Set;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+type Alt = (Vec<(usize, usize)>, Bits);
+
+/// Finds a shortest alternation by trying every one in order of length, up
+/// to `max_len` chars or until `budget` runs out.
+///
+/// Only what an expression matches matters, so for parts and alternations
+/// alike only the first (so shortest) one matching each set of winners is
+/// kept, and anything matching a loser is thrown away. Any part worth having
+/// matches a winner, so is a dotted substring of it, which keeps the parts
+/// to try down, without the 4 char limit of `subparts`.
+pub fn synthesize(
+    winners: &Set,
+    losers: &Set,
+    max_len: usize,
+    budget: Duration,
+) -> Option<Vec<Regex>> {
+    let deadline = Instant::now() + budget;
+    let words = winners.iter().copied().sorted().collect_vec();
+    let full = Bits::full(words.len());
+
+    // Indexed by cost, then by when they were found. Alternations hold the
+    // indices of their parts.
+    let mut parts: Vec<Vec<(Regex, Bits)>> = vec![vec![]];
+    let mut alts: Vec<Vec<Alt>> = vec![vec![]];
+    let mut parts_seen = HashSet::new();
+    let mut alts_seen = HashSet::new();
+
+    for len in 1..=max_len {
+        let mut new_parts = vec![];
+        for part in parts_of_cost(&words, len) {
+            if Instant::now() > deadline {
+                return None;
+            }
+            let part = Regex::new(part);
+            if losers.iter().any(|loser| part.is_match(loser)) {
+                continue;
+            }
+            let mut hits = Bits::new(words.len());
+            for (n, word) in words.iter().enumerate() {
+                if part.is_match(word) {
+                    hits.insert(n);
+                }
+            }
+            if parts_seen.insert(hits.clone()) {
+                new_parts.push((part, hits));
+            }
+        }
+        parts.push(new_parts);
+
+        // A lone part, or a shorter alternation, a `|`, and a part
+        let lone = (0..parts[len].len()).map(|n| (vec![], (len, n)));
+        let joined = (1..len.saturating_sub(1)).flat_map(|head| {
+            let tail = len - head - 1;
+            alts[head]
+                .iter()
+                .cartesian_product(0..parts[tail].len())
+                .map(move |((alt, _), n)| (alt.clone(), (tail, n)))
+        });
+        let mut new_alts = vec![];
+        for (mut alt, part) in lone.chain(joined) {
+            if Instant::now() > deadline {
+                return None;
+            }
+            let mut hits = parts[part.0][part.1].1.clone();
+            for &(cost, n) in &alt {
+                hits.union_with(&parts[cost][n].1);
+            }
+            alt.push(part);
+            if hits == full {
+                return Some(
+                    alt.into_iter()
+                        .map(|(cost, n)| parts[cost][n].0.clone())
+                        .collect(),
+                );
+            }
+            if alts_seen.insert(hits.clone()) {
+                new_alts.push((alt, hits));
+            }
+        }
+        alts.push(new_alts);
+    }
+    None
+}
+
+/// Every dotted substring of a winner that costs `cost` with its anchors
+fn parts_of_cost<'a>(words: &'a [&str], cost: usize) -> impl Iterator<Item = String> + 'a {
+    const ANCHORS: [(bool, bool); 4] = [(false, false), (true, false), (false, true), (true, true)];
+    words
+        .iter()
+        .cartesian_product(ANCHORS.iter().copied())
+        .filter_map(move |(word, (front, back))| {
+            let len = cost.checked_sub(front as usize + back as usize)?;
+            if len == 0 || len > word.len() {
+                return None;
+            }
+            let starts = if front { 0..1 } else { 0..word.len() - len + 1 };
+            Some(
+                starts
+                    .filter(move |start| !back || start + len == word.len())
+                    .cartesian_product(0..1usize << len)
+                    .map(move |(start, mask)| {
+                        let body: String = word[start..start + len]
+                            .chars()
+                            .enumerate()
+                            .map(|(i, x)| if (mask >> i) & 1 != 0 { '.' } else { x })
+                            .collect();
+                        format!(
+                            "{}{}{}",
+                            if front { "^" } else { "" },
+                            body,
+                            if back { "$" } else { "" }
+                        )
+                    }),
+            )
+        })
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest() {
+        let found = |winners: &[&str], losers: &[&str]| {
+            let winners: Set = winners.iter().copied().collect();
+            let losers: Set = losers.iter().copied().collect();
+            synthesize(&winners, &losers, 8, Duration::from_secs(10))
+                .map(|x| x.into_iter().map(|x| x.to_string()).join("|"))
+        };
+        assert_eq!(found(&["abc", "abd"], &["xyz"]), Some("a".to_owned()));
+        assert_eq!(found(&["abc", "xbd"], &["b", "ab"]), Some("b.".to_owned()));
+        assert_eq!(
+            found(&["ab", "ba"], &["aa", "bb"]),
+            Some("ab|ba".to_owned())
+        );
+        assert_eq!(found(&["abc"], &["abc"]), None);
+    }
+}
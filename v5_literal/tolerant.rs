@@ -0,0 +1,313 @@
+use crate::cover::{prune, Bits};
+use crate::h4x_re::Regex;
+use crate::{cuts, regex_covers, score, weighted_greedy, Set, Weights};
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Objective {
+    /// Shortest regex missing at most `misses` winners and matching at most
+    /// `wrong` losers
+    Budget { misses: usize, wrong: usize },
+    /// Regex golf scoring: `points` per winner matched, minus `points` per
+    /// loser matched, minus the length
+    Golf { points: i64 },
+}
+
+pub struct Tolerant<'a> {
+    pub parts: Vec<Regex>,
+    /// Winners left unmatched
+    pub missed: Vec<&'a str>,
+    /// Losers matched anyway
+    pub wrong: Vec<&'a str>,
+    /// False if no part could be added without matching too many losers
+    /// before enough winners were matched, so `missed` is over the budget
+    pub fits: bool,
+}
+
+struct Pool<'a> {
+    winners: Vec<&'a str>,
+    losers: Vec<&'a str>,
+    win_weights: Vec<i64>,
+    lose_weights: Vec<i64>,
+    // Parts sorted by cost then text, with the winners and losers they match
+    parts: Vec<(Regex, Bits, Bits)>,
+}
+
+impl Pool<'_> {
+    fn weight(&self, part: usize) -> i64 {
+        self.parts[part].0.cost() as i64 + 1
+    }
+
+    fn hits(&self, chosen: &[usize]) -> (Bits, Bits) {
+        let mut wins = Bits::new(self.winners.len());
+        let mut wrong = Bits::new(self.losers.len());
+        for &part in chosen {
+            wins.union_with(&self.parts[part].1);
+            wrong.union_with(&self.parts[part].2);
+        }
+        (wins, wrong)
+    }
+
+    fn fits(&self, objective: Objective, chosen: &[usize]) -> bool {
+        let (wins, wrong) = self.hits(chosen);
+        match objective {
+            Objective::Budget {
+                misses,
+                wrong: most,
+            } => self.winners.len() - wins.count() <= misses && wrong.count() <= most,
+            Objective::Golf { .. } => true,
+        }
+    }
+
+    fn won(&self, wins: &Bits) -> i64 {
+        wins.iter().map(|x| self.win_weights[x]).sum()
+    }
+
+    fn lost(&self, wrong: &Bits) -> i64 {
+        wrong.iter().map(|x| self.lose_weights[x]).sum()
+    }
+
+    /// Higher is better
+    fn score(&self, objective: Objective, chosen: &[usize]) -> i64 {
+        let len = chosen.iter().map(|&x| self.weight(x)).sum::<i64>() - 1;
+        let (wins, wrong) = self.hits(chosen);
+        match objective {
+            Objective::Budget { .. } => -len.max(0),
+            Objective::Golf { points } => {
+                points * (self.won(&wins) - self.lost(&wrong)) - len.max(0)
+            }
+        }
+    }
+
+    /// The part adding the most to `chosen` (winners over length under a
+    /// budget, score for golf), with how much it adds
+    fn best_part(&self, objective: Objective, chosen: &[usize]) -> Option<(i64, usize)> {
+        let (wins, wrong) = self.hits(chosen);
+        (0..self.parts.len())
+            .filter_map(|part| {
+                let (_, new_wins, new_wrong) = &self.parts[part];
+                let mut added = new_wins.clone();
+                added.remove_all(&wins);
+                if added.is_empty() {
+                    return None;
+                }
+                let mut also_wrong = new_wrong.clone();
+                also_wrong.remove_all(&wrong);
+                let gain = match objective {
+                    Objective::Budget { wrong: most, .. } => {
+                        if wrong.count() + also_wrong.count() > most {
+                            return None;
+                        }
+                        // Winners per char, scaled to stay an integer
+                        self.won(&added) * 1000 / self.weight(part)
+                    }
+                    Objective::Golf { points } => {
+                        let len = self.weight(part) - chosen.is_empty() as i64;
+                        points * (self.won(&added) - self.lost(&also_wrong)) - len
+                    }
+                };
+                Some((gain, Reverse(part)))
+            })
+            // Ties go to the first, so the cheapest
+            .max()
+            .map(|(gain, Reverse(part))| (gain, part))
+    }
+
+    /// Takes the best part until nothing helps, or a budget is kept to
+    fn greedy(&self, objective: Objective) -> Vec<usize> {
+        let mut chosen = vec![];
+        loop {
+            if let Objective::Budget { .. } = objective {
+                if self.fits(objective, &chosen) {
+                    return chosen;
+                }
+            }
+            match self.best_part(objective, &chosen) {
+                Some((gain, part)) if gain > 0 => chosen.push(part),
+                _ => return chosen,
+            }
+        }
+    }
+
+    /// Drops parts the others cover, as they only add length and losers.
+    /// Then drops each part, or swaps it for the best part without it, while
+    /// that scores higher and still fits.
+    fn improve(&self, objective: Objective, chosen: Vec<usize>) -> Vec<usize> {
+        let prune = |x| {
+            prune(
+                x,
+                self.winners.len(),
+                |x| &self.parts[x].1,
+                |x| self.weight(x) as usize,
+            )
+        };
+        let mut chosen = prune(chosen);
+        let mut i = 0;
+        while i < chosen.len() {
+            let mut without = chosen.clone();
+            without.remove(i);
+            let swapped = self.best_part(objective, &without).map(|(_, part)| {
+                let mut swapped = without.clone();
+                swapped.push(part);
+                swapped
+            });
+            let better = std::iter::once(without)
+                .chain(swapped)
+                .filter(|x| self.fits(objective, x))
+                .map(|x| (self.score(objective, &x), x))
+                .max_by_key(|(score, _)| *score)
+                .filter(|(score, _)| *score > self.score(objective, &chosen));
+            match better {
+                Some((_, next)) => {
+                    chosen = prune(next);
+                    i = 0;
+                }
+                None => i += 1,
+            }
+        }
+        chosen
+    }
+}
+
+/// Like `find_regex`, but winners may be missed and losers matched when
+/// `objective` allows it. Words count their weight towards the score, but
+/// only 1 towards a budget. If the budget can't be kept to, the answer
+/// matching the most winners found is returned, with `fits` false.
+///
+/// Parts are any dotted substring of a winner, loser or not. Greedy picks
+/// the part adding the most until nothing helps, and for golf, greedy under
+/// a budget of one miss and one wrong is tried too. Each of those, and the
+/// usual answer matching no loser, is improved by swapping parts, and the
+/// best scoring is kept.
+pub fn solve<'a>(
+    winners: &Set<'a>,
+    losers: &Set<'a>,
+    win_weights: &Weights,
+    lose_weights: &Weights,
+    objective: Objective,
+) -> Tolerant<'a> {
+    // The usual answer, unless a winner is also a loser and can't be matched
+    // without one
+    let plain = if winners.iter().all(|x| !losers.contains(x)) {
+        let covers = regex_covers(winners, losers);
+        Some(weighted_greedy(
+            covers,
+            winners,
+            win_weights,
+            &score::Linear(4),
+            None,
+        ))
+    } else {
+        None
+    };
+    let winners = winners.iter().copied().sorted().collect_vec();
+    let losers = losers.iter().copied().sorted().collect_vec();
+    let mut seen = HashSet::new();
+    let mut parts = vec![];
+    for word in &winners {
+        for part in cuts(format!("^{}$", word)) {
+            if !seen.insert(part.clone()) {
+                continue;
+            }
+            let hits = |words: &[&str]| {
+                let mut hits = Bits::new(words.len());
+                for (n, word) in words.iter().enumerate() {
+                    if part.is_match(word) {
+                        hits.insert(n);
+                    }
+                }
+                hits
+            };
+            let (wins, wrong) = (hits(&winners), hits(&losers));
+            parts.push((part, wins, wrong));
+        }
+    }
+    parts.sort_by_cached_key(|(part, _, _)| (part.cost(), part.to_string()));
+    let weights = |words: &[&str], weights: &Weights| {
+        words
+            .iter()
+            .map(|x| weights.get(&x.as_ptr()).map_or(1, |&x| x as i64))
+            .collect()
+    };
+    let pool = Pool {
+        win_weights: weights(&winners, win_weights),
+        lose_weights: weights(&losers, lose_weights),
+        winners,
+        losers,
+        parts,
+    };
+
+    let mut starts = vec![pool.greedy(objective)];
+    if let Objective::Golf { .. } = objective {
+        starts.push(pool.greedy(Objective::Budget {
+            misses: 1,
+            wrong: 1,
+        }));
+    }
+    if let Some(plain) = plain {
+        // `regex_covers` cuts the same parts, so they're all in the pool
+        let index: HashMap<&Regex, usize> = pool
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(n, (part, _, _))| (part, n))
+            .collect();
+        starts.push(plain.iter().map(|x| index[x]).collect());
+    }
+    let mut chosen = starts
+        .into_iter()
+        .map(|x| pool.improve(objective, x))
+        .enumerate()
+        // Ties go to the first
+        .max_by_key(|(n, x)| {
+            (
+                pool.fits(objective, x),
+                pool.score(objective, x),
+                Reverse(*n),
+            )
+        })
+        .unwrap()
+        .1;
+    chosen.sort_unstable();
+
+    let (wins, wrong) = pool.hits(&chosen);
+    Tolerant {
+        parts: chosen.iter().map(|&x| pool.parts[x].0.clone()).collect(),
+        missed: (0..pool.winners.len())
+            .filter(|&x| !wins.contains(x))
+            .map(|x| pool.winners[x])
+            .collect(),
+        wrong: wrong.iter().map(|x| pool.losers[x]).collect(),
+        fits: pool.fits(objective, &chosen),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data, greedy};
+
+    #[test]
+    fn golf_beats_plain() {
+        let pharma: Set = data::PHARMA.iter().copied().collect();
+        let cities: Set = data::CITIES.iter().copied().collect();
+        let points = 10;
+        let len = |parts: &[Regex]| parts.iter().map(|x| x.to_string()).join("|").len() as i64;
+
+        let golf = solve(
+            &pharma,
+            &cities,
+            &Weights::new(),
+            &Weights::new(),
+            Objective::Golf { points },
+        );
+        let won = (pharma.len() - golf.missed.len()) as i64;
+        let golf = points * (won - golf.wrong.len() as i64) - len(&golf.parts);
+        let plain = greedy(regex_covers(&pharma, &cities), &pharma, &score::Linear(4));
+        let plain = points * pharma.len() as i64 - len(&plain);
+
+        assert!(golf >= plain);
+    }
+}
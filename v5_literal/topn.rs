@@ -0,0 +1,104 @@
+use crate::cover::{Bits, Limits, Table};
+use crate::{beam, exact, score};
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::time::{Duration, Instant};
+
+// Cost, parts, then the parts the answers under it must and mustn't use
+type Node = (usize, Vec<usize>, Vec<usize>, Vec<usize>);
+
+pub struct Top {
+    pub answers: Vec<Vec<usize>>,
+    /// False if any subproblem ran out of time, so a cheaper answer may be
+    /// missing and the ranking may be wrong
+    pub proven: bool,
+}
+
+/// The `n` cheapest covers that differ in at least one part, cheapest first,
+/// with ties going to the one with the earlier parts.
+///
+/// Lawler's method: once an answer is taken, the rest of its space is split
+/// into one subproblem per part, each banning that part and forcing the
+/// ones before it, so no answer turns up twice. Each subproblem is solved
+/// by branch and bound until `budget` runs out, and the ranking is only
+/// proven if every one of them finishes.
+pub fn top(table: &Table, n: usize, budget: Duration) -> Top {
+    let start = Instant::now();
+    let mut heap = BinaryHeap::new();
+    // The best answer gets half the time, as everything else is judged by it
+    let (first, mut proven) = solve(table, vec![], vec![], start + budget / 2);
+    if let Some(node) = first {
+        heap.push(Reverse(node));
+    }
+    let mut found = vec![];
+    while found.len() < n {
+        let (_, parts, forced, banned) = match heap.pop() {
+            Some(Reverse(x)) => x,
+            None => break,
+        };
+        let free = parts.iter().filter(|x| !forced.contains(x)).collect_vec();
+        for (i, &&part) in free.iter().enumerate() {
+            let forced = forced.iter().chain(free[..i].iter().copied()).copied();
+            let banned = banned.iter().copied().chain(Some(part));
+            let (node, finished) = solve(table, forced.collect(), banned.collect(), start + budget);
+            proven &= finished;
+            if let Some(node) = node {
+                heap.push(Reverse(node));
+            }
+        }
+        found.push(parts);
+    }
+    // Unproven subproblems can come out of order
+    found.sort_by_cached_key(|x| (table.cost(x), x.clone()));
+    Top {
+        answers: found,
+        proven,
+    }
+}
+
+/// The cheapest answer using `forced` and not `banned`, if there is one,
+/// and whether it was proven cheapest before `deadline`
+fn solve(
+    table: &Table,
+    forced: Vec<usize>,
+    banned: Vec<usize>,
+    deadline: Instant,
+) -> (Option<Node>, bool) {
+    let mut words = Bits::full(table.words.len());
+    for &part in &forced {
+        words.remove_all(&table.hits[part]);
+    }
+    let allowed = (0..table.parts.len())
+        .filter(|x| !forced.contains(x) && !banned.contains(x))
+        .filter(|&x| table.hits[x].and_count(&words) != 0)
+        .collect_vec();
+    let rest = table.restrict(&words, &allowed);
+
+    let mut left = Bits::full(rest.words.len());
+    for hits in &rest.hits {
+        left.remove_all(hits);
+    }
+    if !left.is_empty() {
+        return (None, true);
+    }
+    let (chosen, proven) = if rest.words.is_empty() {
+        (vec![], true)
+    } else {
+        let limits = Limits {
+            deadline: Some(deadline),
+            ..Default::default()
+        };
+        let upper = beam::beam(&rest, 1, &score::Linear(4), Limits::default());
+        let solved = exact::solve(&rest, &upper, limits);
+        (solved.parts, solved.proven)
+    };
+
+    let parts = forced
+        .iter()
+        .copied()
+        .chain(chosen.iter().map(|&x| allowed[x]))
+        .sorted()
+        .collect_vec();
+    (Some((table.cost(&parts), parts, forced, banned)), proven)
+}